@@ -1,37 +1,30 @@
 use ark_bls12_381::Fq;
 use ark_ff::UniformRand;
-use circuit::{circuit::Circuit, gate::Gate, gate::Op, layer::Layer};
-use criterion::{Criterion, black_box};
+use circuit::{CircuitBuilder, circuit::Circuit, gate::Op, layer::Layer};
+use criterion::{BatchSize, Criterion, black_box};
 use rand::Rng;
 
 fn build_sample_circuit(num_of_layers: usize) -> Circuit<Fq> {
     let input_size = 1 << num_of_layers;
     let mut i = input_size;
-    let mut layers: Vec<Layer<Fq>> = vec![];
+    let mut builder = CircuitBuilder::new();
 
     while i > 1 {
         let mut layer = vec![];
 
         for j in (0..i).step_by(2) {
             let rng = rand::thread_rng().gen_range(0..1);
-            let gate: Gate;
+            let op = if rng == 1 { Op::Add } else { Op::Mul };
 
-            if rng == 1 {
-                gate = Gate::new(Op::Add, j / 2, j, j + 1);
-            } else {
-                gate = Gate::new(Op::Mul, j / 2, j, j + 1);
-            }
-
-            layer.push(gate);
+            layer.push((op, j, j + 1));
         }
 
-        layers.push(Layer::new(layer));
+        builder = builder.add_layer(layer);
 
         i = i / 2;
     }
 
-    layers.reverse();
-    Circuit::new(layers)
+    builder.build()
 }
 
 pub fn circuit_benchmarks(c: &mut Criterion) {
@@ -52,10 +45,37 @@ pub fn circuit_benchmarks(c: &mut Criterion) {
         });
     });
 
+    group.bench_function(
+        format!("circuit evaluate sequential {} inputs", input_size),
+        |b| {
+            b.iter(|| {
+                black_box(circuit.evaluate_sequential(input.clone()));
+            });
+        },
+    );
+
+    #[cfg(feature = "parallel")]
+    group.bench_function(
+        format!("circuit evaluate parallel {} inputs", input_size),
+        |b| {
+            b.iter(|| {
+                black_box(circuit.evaluate_parallel(input.clone()));
+            });
+        },
+    );
+
     for i in (0..num_of_layers).rev() {
         group.bench_function(format!("mle generation layer {}", i + 1), |b| {
+            b.iter_batched(
+                || Layer::<Fq>::new(circuit.layers[i].gates.clone()),
+                |layer| black_box(layer.add_i_and_mul_i_polynomials()),
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_function(format!("cached mle lookup layer {}", i + 1), |b| {
             b.iter(|| {
-                black_box(circuit.add_i_and_mul_i_polynomials(i as usize));
+                black_box(circuit.add_i_and_mul_i_polynomials(i));
             });
         });
     }
@@ -0,0 +1,116 @@
+use crate::{
+    circuit::Circuit,
+    gate::{Gate, Op},
+    layer::Layer,
+};
+use ark_ff::PrimeField;
+use std::marker::PhantomData;
+
+// builds a circuit bottom-up: the first layer added reads the circuit input and the last one
+// produces the circuit output. gates get their output index from their position in the layer,
+// so only the op and the two input wires are given.
+#[derive(Debug, Default)]
+pub struct CircuitBuilder<F: PrimeField> {
+    layers: Vec<Vec<Gate>>,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: PrimeField> CircuitBuilder<F> {
+    pub fn new() -> Self {
+        Self {
+            layers: vec![],
+            _phantom: PhantomData,
+        }
+    }
+
+    // starts a new layer above the current one with the given (op, left, right) gates
+    pub fn add_layer(mut self, gates: Vec<(Op, usize, usize)>) -> Self {
+        self.layers.push(vec![]);
+
+        for (op, left_index, right_index) in gates {
+            self = self.add_gate(op, left_index, right_index);
+        }
+
+        self
+    }
+
+    // appends a gate to the current (topmost) layer
+    pub fn add_gate(mut self, op: Op, left_index: usize, right_index: usize) -> Self {
+        if self.layers.is_empty() {
+            self.layers.push(vec![]);
+        }
+
+        let layer = self.layers.last_mut().expect("at least one layer exists");
+        let output = layer.len();
+
+        layer.push(Gate::binary(op, output, left_index, right_index));
+
+        self
+    }
+
+    pub fn build(self) -> Circuit<F> {
+        assert!(
+            self.layers.iter().all(|layer| !layer.is_empty()),
+            "Every layer must contain at least one gate"
+        );
+
+        for (i, pair) in self.layers.windows(2).enumerate() {
+            let input_size = pair[0].len();
+
+            assert!(
+                pair[1]
+                    .iter()
+                    .all(|gate| gate.inputs.iter().all(|&input| input < input_size)),
+                "Gate in layer {} reads outside of the {} outputs of the layer below",
+                i + 1,
+                input_size
+            );
+        }
+
+        // `Circuit` stores its layers output first
+        let layers = self.layers.into_iter().rev().map(Layer::new).collect();
+
+        Circuit::new(layers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+
+    fn fq(val: u64) -> Fq {
+        Fq::from(val)
+    }
+
+    #[test]
+    fn test_build_and_evaluate() {
+        // (1 + 2) + (3 * 4) = 15
+        let mut circuit = CircuitBuilder::<Fq>::new()
+            .add_layer(vec![(Op::Add, 0, 1), (Op::Mul, 2, 3)])
+            .add_layer(vec![])
+            .add_gate(Op::Add, 0, 1)
+            .build();
+
+        assert_eq!(
+            circuit.evaluate(vec![fq(1), fq(2), fq(3), fq(4)]),
+            vec![fq(15)]
+        );
+        assert_eq!(circuit.layers[1].gates[1], Gate::binary(Op::Mul, 1, 2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Gate in layer 1 reads outside of the 2 outputs of the layer below")]
+    fn test_build_rejects_dangling_wire() {
+        CircuitBuilder::<Fq>::new()
+            .add_layer(vec![(Op::Add, 0, 1), (Op::Mul, 2, 3)])
+            .add_layer(vec![(Op::Add, 0, 2)])
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Circuit must contain at least one layer")]
+    fn test_build_empty_circuit() {
+        CircuitBuilder::<Fq>::new().build();
+    }
+}
@@ -1,3 +1,4 @@
+use crate::gate::Op;
 use crate::layer::Layer;
 use ark_ff::PrimeField;
 use polynomials::{
@@ -18,43 +19,115 @@ impl<F: PrimeField> Circuit<F> {
             "Circuit must contain at least one layer"
         );
 
-        let layer_evals = vec![vec![]; 1 << (layers.len() - 1)];
-
         Circuit {
             layers,
-            layer_evals,
+            layer_evals: vec![],
         }
     }
 
     pub fn evaluate(&mut self, initial_layer_eval: Vec<F>) -> Vec<F> {
+        #[cfg(feature = "parallel")]
+        {
+            self.evaluate_parallel(initial_layer_eval)
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            self.evaluate_sequential(initial_layer_eval)
+        }
+    }
+
+    pub fn evaluate_sequential(&mut self, initial_layer_eval: Vec<F>) -> Vec<F> {
+        self.evaluate_with(initial_layer_eval, Layer::evaluate)
+    }
+
+    #[cfg(feature = "parallel")]
+    pub fn evaluate_parallel(&mut self, initial_layer_eval: Vec<F>) -> Vec<F> {
+        self.evaluate_with(initial_layer_eval, Layer::evaluate_parallel)
+    }
+
+    fn evaluate_with(
+        &mut self,
+        initial_layer_eval: Vec<F>,
+        evaluate_layer: fn(&Layer<F>, &[F]) -> Vec<F>,
+    ) -> Vec<F> {
         let mut current_layer_eval = initial_layer_eval;
         let mut resultant_evals = Vec::with_capacity(self.layers.len() + 1);
 
         resultant_evals.push(current_layer_eval.clone());
 
         for layer in self.layers.iter().rev() {
-            let mut max_layer_index = 0;
+            current_layer_eval = evaluate_layer(layer, &current_layer_eval);
+            resultant_evals.push(current_layer_eval.clone());
+        }
 
-            for gate in &layer.gates {
-                max_layer_index = max_layer_index.max(gate.output);
-            }
+        resultant_evals.reverse();
+        self.layer_evals = resultant_evals.clone();
+
+        resultant_evals[0].clone()
+    }
+
+    // updates the given input positions of the last evaluation and recomputes only the
+    // gates whose value can depend on them, layer by layer up to the output
+    pub fn evaluate_incremental(&mut self, changed: &[(usize, F)]) -> Vec<F> {
+        assert!(
+            !self.layer_evals.is_empty(),
+            "Circuit must be evaluated before it can be re-evaluated incrementally"
+        );
 
-            let mut evals = vec![F::ZERO; max_layer_index + 1];
+        let input_layer = self.layers.len();
+        let input = &mut self.layer_evals[input_layer];
+        let mut changed_positions = Vec::with_capacity(changed.len());
 
-            for gate in layer.gates.iter() {
-                let current_gate_eval = gate.eval_gate(&current_layer_eval);
+        for &(position, value) in changed {
+            assert!(
+                position < input.len(),
+                "Input position {} out of bounds for {} inputs",
+                position,
+                input.len()
+            );
 
-                evals[gate.output] += current_gate_eval;
+            input[position] = value;
+            changed_positions.push(position);
+        }
+
+        for (layer_index, layer) in self.layers.iter().enumerate().rev() {
+            if changed_positions.is_empty() {
+                break;
             }
 
-            current_layer_eval = evals;
-            resultant_evals.push(current_layer_eval.clone());
+            let (outputs, inputs) = self.layer_evals.split_at_mut(layer_index + 1);
+            changed_positions =
+                layer.reevaluate(&inputs[0], &changed_positions, &mut outputs[layer_index]);
         }
 
-        resultant_evals.reverse();
-        self.layer_evals = resultant_evals.clone();
+        self.layer_evals[0].clone()
+    }
 
-        resultant_evals[0].clone()
+    // binds the wiring into a transcript: the layer count, then per layer its gate count
+    // followed by each gate's op, output, fan-in and inputs, all as big-endian u64s
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = (self.layers.len() as u64).to_be_bytes().to_vec();
+
+        for layer in &self.layers {
+            bytes.extend((layer.gates.len() as u64).to_be_bytes());
+
+            for gate in &layer.gates {
+                let op: u64 = match gate.op {
+                    Op::Add => 0,
+                    Op::Mul => 1,
+                };
+
+                for value in [op, gate.output as u64, gate.inputs.len() as u64]
+                    .into_iter()
+                    .chain(gate.inputs.iter().map(|&input| input as u64))
+                {
+                    bytes.extend(value.to_be_bytes());
+                }
+            }
+        }
+
+        bytes
     }
 
     pub fn add_i_and_mul_i_polynomials(
@@ -64,13 +137,38 @@ impl<F: PrimeField> Circuit<F> {
         self.layers[layer_index].add_i_and_mul_i_polynomials()
     }
 
-    pub fn w_i_polynomial(&self, layer_index: usize) -> MultilinearPolynomial<F> {
+    // selector pairs of every layer, output layer first. each layer caches its own pair, so
+    // this is cheap to call again once the selectors have been built
+    pub fn all_wiring_polynomials(
+        &self,
+    ) -> Vec<(MultilinearPolynomial<F>, MultilinearPolynomial<F>)> {
+        (0..self.num_layers())
+            .map(|layer_index| self.add_i_and_mul_i_polynomials(layer_index))
+            .collect()
+    }
+
+    pub fn num_layers(&self) -> usize {
+        self.layers.len()
+    }
+
+    // MLE of the cached evaluations of layer `layer_index`, where 0 is the output layer and
+    // `num_layers()` is the circuit input. padded with zeros to a power of two, matching the
+    // padded gate positions in `Layer::layer_index`.
+    pub fn layer_mle(&self, layer_index: usize) -> MultilinearPolynomial<F> {
+        assert!(
+            !self.layer_evals.is_empty(),
+            "Circuit must be evaluated before its layer evaluations can be used"
+        );
         assert!(
             layer_index < self.layer_evals.len(),
             "Layer index cannot be greater than total number of layers"
         );
 
-        MultilinearPolynomial::new(self.layer_evals[layer_index].clone())
+        MultilinearPolynomial::new_padded(self.layer_evals[layer_index].clone())
+    }
+
+    pub fn w_i_polynomial(&self, layer_index: usize) -> MultilinearPolynomial<F> {
+        self.layer_mle(layer_index)
     }
 
     pub fn f_i_bc_polynomial(&self, layer_index: usize) -> SumPolynomial<F> {
@@ -90,7 +188,8 @@ impl<F: PrimeField> Circuit<F> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::gate::{Gate, Op};
+    use crate::builder::CircuitBuilder;
+    use crate::gate::Gate;
     use ark_bls12_381::Fq;
     use ark_ff::Field;
 
@@ -107,10 +206,10 @@ mod tests {
         // --- Layer 2 (Bottom Layer): 4 inputs -> 2 outputs ---
 
         // Gate 0: Add input[0] + input[1] = 1 + 2 = 3 → stored at index 0 of layer 2 output
-        let layer2_gate1 = Gate::new(Op::Add, 0, 0, 1);
+        let layer2_gate1 = Gate::binary(Op::Add, 0, 0, 1);
 
         // Gate 1: Mul input[2] * input[3] = 3 * 4 = 12 → stored at index 1 of layer 2 output
-        let layer_2gate2 = Gate::new(Op::Mul, 1, 2, 3);
+        let layer_2gate2 = Gate::binary(Op::Mul, 1, 2, 3);
 
         // Layer 2 has two gates, producing [3, 12]
         let layer2 = Layer::new(vec![layer_2gate2, layer2_gate1]);
@@ -118,7 +217,7 @@ mod tests {
         // --- Layer 1 (Middle Layer): 2 inputs → 1 output ---
 
         // Gate: Add layer2_output[0] + layer2_output[1] = 3 + 12 = 15 → output of entire circuit
-        let layer1_gate1 = Gate::new(Op::Add, 0, 0, 1);
+        let layer1_gate1 = Gate::binary(Op::Add, 0, 0, 1);
 
         // Layer 1 has one gate, producing [15]
         let layer1 = Layer::new(vec![layer1_gate1]);
@@ -145,21 +244,102 @@ mod tests {
         assert_eq!(result[0], fq(15));
     }
 
+    #[test]
+    fn test_evaluate_incremental_matches_full_evaluation() {
+        // out[0] = (in0 + in1) * (in2 * in3), out[1] = (in2 * in3) + (in2 * in3)
+        let layers = || {
+            vec![
+                Layer::new(vec![
+                    Gate::binary(Op::Mul, 0, 0, 1),
+                    Gate::binary(Op::Add, 1, 1, 1),
+                ]),
+                Layer::new(vec![
+                    Gate::binary(Op::Add, 0, 0, 1),
+                    Gate::binary(Op::Mul, 1, 2, 3),
+                ]),
+            ]
+        };
+        let mut input = vec![fq(1), fq(2), fq(3), fq(4)];
+
+        let mut incremental = Circuit::<Fq>::new(layers());
+        incremental.evaluate(input.clone());
+
+        for (position, value) in [(0, fq(5)), (3, fq(10)), (2, fq(0))] {
+            input[position] = value;
+
+            let mut full = Circuit::<Fq>::new(layers());
+            let expected = full.evaluate(input.clone());
+
+            assert_eq!(
+                incremental.evaluate_incremental(&[(position, value)]),
+                expected
+            );
+            assert_eq!(incremental.layer_evals, full.layer_evals);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_incremental_recomputes_accumulated_outputs() {
+        // both gates accumulate into output 0, so changing in0 must keep the in2 * in3 part
+        let layers = || {
+            vec![Layer::new(vec![
+                Gate::binary(Op::Add, 0, 0, 1),
+                Gate::binary(Op::Mul, 0, 2, 3),
+            ])]
+        };
+
+        let mut incremental = Circuit::<Fq>::new(layers());
+        incremental.evaluate(vec![fq(1), fq(2), fq(3), fq(4)]);
+
+        let mut full = Circuit::<Fq>::new(layers());
+
+        assert_eq!(
+            incremental.evaluate_incremental(&[(0, fq(7))]),
+            full.evaluate(vec![fq(7), fq(2), fq(3), fq(4)])
+        );
+    }
+
     #[test]
     fn test_gate_eval_add_and_mul() {
         let layer_eval = vec![fq(2), fq(3)];
 
-        let add_gate = Gate::new(Op::Add, 0, 0, 1);
-        let mul_gate = Gate::new(Op::Mul, 0, 0, 1);
+        let add_gate = Gate::binary(Op::Add, 0, 0, 1);
+        let mul_gate = Gate::binary(Op::Mul, 0, 0, 1);
 
         assert_eq!(add_gate.eval_gate(&layer_eval), fq(5));
         assert_eq!(mul_gate.eval_gate(&layer_eval), fq(6));
     }
 
+    #[test]
+    fn test_gate_eval_nary() {
+        let layer_eval = vec![fq(2), fq(3), fq(5), fq(7)];
+
+        let add_gate = Gate::new(Op::Add, 0, vec![0, 1, 3]);
+        let mul_gate = Gate::new(Op::Mul, 0, vec![0, 1, 3]);
+
+        assert_eq!(add_gate.eval_gate(&layer_eval), fq(12));
+        assert_eq!(mul_gate.eval_gate(&layer_eval), fq(42));
+
+        let mut circuit = Circuit::<Fq>::new(vec![Layer::new(vec![add_gate, mul_gate])]);
+
+        // both gates write to output 0, so their results are accumulated
+        assert_eq!(circuit.evaluate(layer_eval), vec![fq(54)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Selector polynomials are only defined for fan-in 2 gates, got a gate with 3 inputs"
+    )]
+    fn test_selectors_of_nary_gate_panic() {
+        let layer = Layer::<Fq>::new(vec![Gate::new(Op::Add, 0, vec![0, 1, 2])]);
+
+        layer.add_i_and_mul_i_polynomials();
+    }
+
     #[test]
     fn test_add_i_and_mul_i_polynomials() {
-        let add_gate = Gate::new(Op::Add, 0, 0, 1);
-        let mul_gate = Gate::new(Op::Mul, 1, 1, 2);
+        let add_gate = Gate::binary(Op::Add, 0, 0, 1);
+        let mul_gate = Gate::binary(Op::Mul, 1, 1, 2);
         let layer = Layer::<Fq>::new(vec![add_gate, mul_gate]);
 
         let (add_poly, mul_poly) = layer.add_i_and_mul_i_polynomials();
@@ -179,11 +359,27 @@ mod tests {
         assert_eq!(mul_count, 1);
     }
 
+    #[test]
+    fn test_all_wiring_polynomials() {
+        let circuit = CircuitBuilder::<Fq>::new()
+            .add_layer(vec![(Op::Add, 0, 1), (Op::Mul, 2, 3)])
+            .add_layer(vec![(Op::Mul, 0, 1)])
+            .build();
+
+        let wiring = circuit.all_wiring_polynomials();
+
+        assert_eq!(wiring.len(), 2);
+
+        for (layer_index, selectors) in wiring.iter().enumerate() {
+            assert_eq!(selectors, &circuit.add_i_and_mul_i_polynomials(layer_index));
+        }
+    }
+
     #[test]
     fn test_w_i_polynomial_returns_correct_layer_eval() {
         let input = vec![fq(1), fq(1), fq(1), fq(1)];
-        let gate1 = Gate::new(Op::Add, 0, 0, 1);
-        let gate2 = Gate::new(Op::Mul, 1, 2, 3);
+        let gate1 = Gate::binary(Op::Add, 0, 0, 1);
+        let gate2 = Gate::binary(Op::Mul, 1, 2, 3);
         let layer = Layer::new(vec![gate1, gate2]);
 
         let mut circuit = Circuit::<Fq>::new(vec![layer]);
@@ -198,11 +394,11 @@ mod tests {
     fn test_circuit_evaluation_add_mul_combo() {
         let input = vec![fq(1), fq(2), fq(3), fq(4)];
 
-        let gate_add = Gate::new(Op::Add, 0, 0, 1); // 1 + 2 = 3
-        let gate_mul = Gate::new(Op::Mul, 1, 2, 3); // 3 * 4 = 12
+        let gate_add = Gate::binary(Op::Add, 0, 0, 1); // 1 + 2 = 3
+        let gate_mul = Gate::binary(Op::Mul, 1, 2, 3); // 3 * 4 = 12
         let layer2 = Layer::new(vec![gate_add, gate_mul]);
 
-        let gate_final = Gate::new(Op::Add, 0, 0, 1); // 3 + 12 = 15
+        let gate_final = Gate::binary(Op::Add, 0, 0, 1); // 3 + 12 = 15
         let layer1 = Layer::new(vec![gate_final]);
 
         let mut circuit = Circuit::<Fq>::new(vec![layer1, layer2]);
@@ -223,7 +419,7 @@ mod tests {
     fn test_circuit_with_single_layer_add_only() {
         let input = vec![fq(5), fq(7)];
 
-        let gate = Gate::new(Op::Add, 0, 0, 1); // 5 + 7 = 12
+        let gate = Gate::binary(Op::Add, 0, 0, 1); // 5 + 7 = 12
         let layer = Layer::new(vec![gate]);
 
         let mut circuit = Circuit::<Fq>::new(vec![layer]);
@@ -237,7 +433,7 @@ mod tests {
     fn test_circuit_with_single_layer_mul_only() {
         let input = vec![fq(6), fq(2)];
 
-        let gate = Gate::new(Op::Mul, 0, 0, 1); // 6 * 2 = 12
+        let gate = Gate::binary(Op::Mul, 0, 0, 1); // 6 * 2 = 12
         let layer = Layer::new(vec![gate]);
 
         let mut circuit = Circuit::<Fq>::new(vec![layer]);
@@ -257,7 +453,7 @@ mod tests {
     fn test_invalid_layer_index_panics() {
         let input = vec![fq(1), fq(1)];
 
-        let gate = Gate::new(Op::Add, 0, 0, 1);
+        let gate = Gate::binary(Op::Add, 0, 0, 1);
         let layer = Layer::new(vec![gate]);
 
         let mut circuit = Circuit::<Fq>::new(vec![layer]);
@@ -278,13 +474,13 @@ mod tests {
         // Layer 2:
         // Gate 0: Add 0 + 1 = 2 + 3 = 5
         // Gate 1: Mul 2 * 3 = 4 * 5 = 20
-        let gate1 = Gate::new(Op::Add, 0, 0, 1);
-        let gate2 = Gate::new(Op::Mul, 1, 2, 3);
+        let gate1 = Gate::binary(Op::Add, 0, 0, 1);
+        let gate2 = Gate::binary(Op::Mul, 1, 2, 3);
         let layer2 = Layer::new(vec![gate1, gate2]);
 
         // Layer 1:
         // Gate 0: Mul of 5 * 20 = 100
-        let gate3 = Gate::new(Op::Mul, 0, 0, 1);
+        let gate3 = Gate::binary(Op::Mul, 0, 0, 1);
         let layer1 = Layer::new(vec![gate3]);
 
         let mut circuit = Circuit::<Fq>::new(vec![layer1, layer2]);
@@ -299,19 +495,19 @@ mod tests {
         let input = vec![fq(1), fq(2), fq(3), fq(4), fq(5), fq(6), fq(7), fq(8)];
 
         // --- Layer 2: 8 → 4, mixed ops ---
-        let layer2_gate0 = Gate::new(Op::Add, 0, 0, 1); // 1 + 2 = 3
-        let layer2_gate1 = Gate::new(Op::Mul, 1, 2, 3); // 3 * 4 = 12
-        let layer2_gate2 = Gate::new(Op::Add, 2, 4, 5); // 5 + 6 = 11
-        let layer2_gate3 = Gate::new(Op::Mul, 3, 6, 7); // 7 * 8 = 56
+        let layer2_gate0 = Gate::binary(Op::Add, 0, 0, 1); // 1 + 2 = 3
+        let layer2_gate1 = Gate::binary(Op::Mul, 1, 2, 3); // 3 * 4 = 12
+        let layer2_gate2 = Gate::binary(Op::Add, 2, 4, 5); // 5 + 6 = 11
+        let layer2_gate3 = Gate::binary(Op::Mul, 3, 6, 7); // 7 * 8 = 56
         let layer2 = Layer::new(vec![layer2_gate0, layer2_gate1, layer2_gate2, layer2_gate3]);
 
         // --- Layer 1: 4 → 2, mixed ops ---
-        let layer1_gate0 = Gate::new(Op::Mul, 0, 0, 1); // 3 * 12 = 36
-        let layer1_gate1 = Gate::new(Op::Add, 1, 2, 3); // 11 + 56 = 67
+        let layer1_gate0 = Gate::binary(Op::Mul, 0, 0, 1); // 3 * 12 = 36
+        let layer1_gate1 = Gate::binary(Op::Add, 1, 2, 3); // 11 + 56 = 67
         let layer1 = Layer::new(vec![layer1_gate0, layer1_gate1]);
 
         // --- Layer 0: 2 → 1, mixed ops ---
-        let layer0_gate0 = Gate::new(Op::Add, 0, 0, 1); // 36 + 67 = 103
+        let layer0_gate0 = Gate::binary(Op::Add, 0, 0, 1); // 36 + 67 = 103
         let layer0 = Layer::new(vec![layer0_gate0]);
 
         let mut circuit = Circuit::<Fq>::new(vec![layer0, layer1, layer2]);
@@ -382,4 +578,107 @@ mod tests {
             0
         );
     }
+
+    #[test]
+    fn test_layer_mle() {
+        let mut circuit = CircuitBuilder::<Fq>::new()
+            .add_layer(vec![(Op::Add, 0, 1), (Op::Mul, 2, 3)])
+            .add_layer(vec![(Op::Add, 0, 1)])
+            .build();
+
+        assert_eq!(circuit.num_layers(), 2);
+
+        let output = circuit.evaluate(vec![fq(1), fq(2), fq(3), fq(4)]);
+        let output_mle = circuit.layer_mle(0);
+
+        assert_eq!(output_mle.evals_slice(), &[fq(15)]);
+        assert_eq!(output_mle.evaluate(&[]), output[0]);
+        assert_eq!(circuit.layer_mle(1).evals_slice(), &[fq(3), fq(12)]);
+        assert_eq!(
+            circuit.layer_mle(2).evals_slice(),
+            &[fq(1), fq(2), fq(3), fq(4)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Circuit must be evaluated before its layer evaluations can be used")]
+    fn test_layer_mle_before_evaluate() {
+        let circuit = Circuit::<Fq>::new(vec![Layer::new(vec![Gate::binary(Op::Add, 0, 0, 1)])]);
+
+        circuit.layer_mle(0);
+    }
+
+    #[test]
+    fn test_to_bytes_binds_wiring() {
+        let circuit = CircuitBuilder::<Fq>::new()
+            .add_layer(vec![(Op::Add, 0, 1), (Op::Mul, 2, 3)])
+            .build();
+        let rewired = CircuitBuilder::<Fq>::new()
+            .add_layer(vec![(Op::Add, 1, 0), (Op::Mul, 2, 3)])
+            .build();
+
+        // per gate: op, output, fan-in and the two inputs
+        assert_eq!(circuit.to_bytes().len(), 8 + 8 + 2 * 5 * 8);
+        assert_ne!(circuit.to_bytes(), rewired.to_bytes());
+    }
+
+    #[test]
+    fn test_non_power_of_two_layer_w_i_is_padded() {
+        let input = vec![fq(1), fq(2), fq(3), fq(4), fq(5), fq(6)];
+
+        let layer = Layer::new(vec![
+            Gate::binary(Op::Add, 0, 0, 1),
+            Gate::binary(Op::Mul, 1, 2, 3),
+            Gate::binary(Op::Add, 2, 4, 5),
+        ]);
+
+        let mut circuit = Circuit::<Fq>::new(vec![layer]);
+
+        assert_eq!(circuit.evaluate(input), vec![fq(3), fq(12), fq(11)]);
+        assert_eq!(
+            circuit.w_i_polynomial(0).evals_slice(),
+            &[fq(3), fq(12), fq(11), fq(0)]
+        );
+        assert_eq!(
+            circuit.w_i_polynomial(1).evals_slice(),
+            &[fq(1), fq(2), fq(3), fq(4), fq(5), fq(6), fq(0), fq(0)]
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_evaluation_matches_sequential() {
+        use ark_ff::UniformRand;
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        let num_of_layers = 6;
+        let mut layers = vec![];
+
+        for layer_vars in (0..num_of_layers).rev() {
+            let gates = (0..1 << layer_vars)
+                .map(|i| {
+                    let op = if rng.gen_bool(0.5) { Op::Add } else { Op::Mul };
+
+                    Gate::binary(op, i, 2 * i, 2 * i + 1)
+                })
+                .collect();
+
+            layers.push(Layer::new(gates));
+        }
+
+        layers.reverse();
+
+        let input: Vec<Fq> = (0..1 << num_of_layers)
+            .map(|_| Fq::rand(&mut rng))
+            .collect();
+        let mut circuit = Circuit::new(layers);
+
+        let sequential = circuit.evaluate_sequential(input.clone());
+        let sequential_layer_evals = circuit.layer_evals.clone();
+        let parallel = circuit.evaluate_parallel(input);
+
+        assert_eq!(sequential, parallel);
+        assert_eq!(sequential_layer_evals, circuit.layer_evals);
+    }
 }
@@ -1,36 +1,49 @@
 use ark_ff::PrimeField;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Op {
     Add,
     Mul,
 }
 
-#[derive(Debug)]
+// a gate folds its op over any number of inputs from the layer below. evaluation supports
+// any fan-in, but the add_i/mul_i selector polynomials (and so GKR) are only defined for
+// fan-in 2 gates, see `binary_inputs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Gate {
     pub op: Op,
     pub output: usize,
-    pub left_index: usize,
-    pub right_index: usize,
+    pub inputs: Vec<usize>,
 }
 
 impl Gate {
-    pub fn new(op: Op, output: usize, left_index: usize, right_index: usize) -> Self {
-        Gate {
-            op,
-            output,
-            left_index,
-            right_index,
+    pub fn new(op: Op, output: usize, inputs: Vec<usize>) -> Self {
+        assert!(!inputs.is_empty(), "A gate needs at least one input");
+
+        Gate { op, output, inputs }
+    }
+
+    pub fn binary(op: Op, output: usize, left_index: usize, right_index: usize) -> Self {
+        Self::new(op, output, vec![left_index, right_index])
+    }
+
+    // (left, right) input of a fan-in 2 gate, as used by the wiring predicates
+    pub fn binary_inputs(&self) -> (usize, usize) {
+        match self.inputs[..] {
+            [left_index, right_index] => (left_index, right_index),
+            _ => panic!(
+                "Selector polynomials are only defined for fan-in 2 gates, got a gate with {} inputs",
+                self.inputs.len()
+            ),
         }
     }
 
     pub fn eval_gate<F: PrimeField>(&self, layer_eval: &[F]) -> F {
-        let left_val = layer_eval[self.left_index];
-        let right_val = layer_eval[self.right_index];
+        let input_vals = self.inputs.iter().map(|&index| layer_eval[index]);
 
         match self.op {
-            Op::Add => left_val + right_val,
-            Op::Mul => left_val * right_val,
+            Op::Add => input_vals.sum(),
+            Op::Mul => input_vals.product(),
         }
     }
 }
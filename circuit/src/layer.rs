@@ -1,20 +1,146 @@
 use crate::gate::{Gate, Op};
 use ark_ff::PrimeField;
-use polynomials::multilinear::MultilinearPolynomial;
-use std::marker::PhantomData;
+use polynomials::multilinear::{MultilinearPolynomial, SparseEvaluationPolynomial};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::sync::{Arc, Mutex, PoisonError};
 
 #[derive(Debug)]
 pub struct Layer<F: PrimeField> {
     pub gates: Vec<Gate>,
-    _phantom: PhantomData<F>,
+    selectors: GateCache<(MultilinearPolynomial<F>, MultilinearPolynomial<F>)>,
+    dependencies: GateCache<GateDependencies>,
+}
+
+// a value derived from the gates, kept next to the gates it was built from so that
+// edits made through the public `gates` field are picked up on the next lookup
+#[derive(Debug)]
+struct GateCache<T>(Mutex<Option<(Vec<Gate>, Arc<T>)>>);
+
+impl<T> GateCache<T> {
+    fn new() -> Self {
+        Self(Mutex::new(None))
+    }
+
+    fn get_or_compute(&self, gates: &[Gate], compute: impl FnOnce() -> T) -> Arc<T> {
+        let mut cached = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match cached.as_ref() {
+            Some((cached_gates, value)) if cached_gates.as_slice() == gates => value.clone(),
+            _ => {
+                let value = Arc::new(compute());
+                *cached = Some((gates.to_vec(), value.clone()));
+
+                value
+            }
+        }
+    }
+}
+
+// which gates read each input position and which gates write each output slot, so a
+// change below can be pushed through only the gates it reaches
+#[derive(Debug)]
+struct GateDependencies {
+    readers: Vec<Vec<usize>>,
+    writers: Vec<Vec<usize>>,
 }
 
 impl<F: PrimeField> Layer<F> {
     pub fn new(gates: Vec<Gate>) -> Self {
         Self {
             gates,
-            _phantom: PhantomData,
+            selectors: GateCache::new(),
+            dependencies: GateCache::new(),
+        }
+    }
+
+    pub fn output_size(&self) -> usize {
+        self.gates.iter().map(|gate| gate.output).max().unwrap_or(0) + 1
+    }
+
+    pub fn evaluate(&self, input: &[F]) -> Vec<F> {
+        let mut evals = vec![F::ZERO; self.output_size()];
+
+        for gate in &self.gates {
+            evals[gate.output] += gate.eval_gate(input);
         }
+
+        evals
+    }
+
+    // recomputes the output slots of `evals` fed by a gate reading one of the
+    // `changed_inputs` positions of `input`, and returns those slots in ascending order
+    pub(crate) fn reevaluate(
+        &self,
+        input: &[F],
+        changed_inputs: &[usize],
+        evals: &mut [F],
+    ) -> Vec<usize> {
+        let dependencies = self
+            .dependencies
+            .get_or_compute(&self.gates, || self.compute_dependencies());
+
+        let mut changed_outputs: Vec<usize> = changed_inputs
+            .iter()
+            .filter_map(|&position| dependencies.readers.get(position))
+            .flatten()
+            .map(|&gate_index| self.gates[gate_index].output)
+            .collect();
+        changed_outputs.sort_unstable();
+        changed_outputs.dedup();
+
+        // a slot accumulates every gate writing to it, not just the ones that changed
+        for &output in &changed_outputs {
+            evals[output] = dependencies.writers[output]
+                .iter()
+                .map(|&gate_index| self.gates[gate_index].eval_gate(input))
+                .sum();
+        }
+
+        changed_outputs
+    }
+
+    fn compute_dependencies(&self) -> GateDependencies {
+        let num_inputs = self
+            .gates
+            .iter()
+            .flat_map(|gate| gate.inputs.iter())
+            .max()
+            .map_or(0, |&max_input| max_input + 1);
+        let mut readers = vec![vec![]; num_inputs];
+        let mut writers = vec![vec![]; self.output_size()];
+
+        for (gate_index, gate) in self.gates.iter().enumerate() {
+            for &input in &gate.inputs {
+                // a gate reading the same position twice only needs recomputing once
+                if readers[input].last() != Some(&gate_index) {
+                    readers[input].push(gate_index);
+                }
+            }
+
+            writers[gate.output].push(gate_index);
+        }
+
+        GateDependencies { readers, writers }
+    }
+
+    // gates only read from the layer below, so they can be evaluated independently and
+    // accumulated into their output slots afterwards
+    #[cfg(feature = "parallel")]
+    pub fn evaluate_parallel(&self, input: &[F]) -> Vec<F> {
+        let gate_evals: Vec<F> = self
+            .gates
+            .par_iter()
+            .map(|gate| gate.eval_gate(input))
+            .collect();
+
+        let mut evals = vec![F::ZERO; self.output_size()];
+
+        for (gate, eval) in self.gates.iter().zip(gate_evals) {
+            evals[gate.output] += eval;
+        }
+
+        evals
     }
 
     pub fn num_layer_vars(&self) -> usize {
@@ -27,12 +153,24 @@ impl<F: PrimeField> Layer<F> {
         }
     }
 
+    // layers whose gate count isn't a power of two are treated as padded up to the next
+    // power of two with zero gates, i.e. layer_index = ceil(log2(len)). The padded positions
+    // are never wired, so they stay zero in the selector polynomials.
     pub fn layer_index(&self) -> usize {
-        self.gates.len().ilog2() as usize
+        self.gates.len().next_power_of_two().ilog2() as usize
     }
 
     pub fn add_i_and_mul_i_polynomials(
         &self,
+    ) -> (MultilinearPolynomial<F>, MultilinearPolynomial<F>) {
+        self.selectors
+            .get_or_compute(&self.gates, || self.compute_add_i_and_mul_i_polynomials())
+            .as_ref()
+            .clone()
+    }
+
+    fn compute_add_i_and_mul_i_polynomials(
+        &self,
     ) -> (MultilinearPolynomial<F>, MultilinearPolynomial<F>) {
         let num_boolean_hypercube_evals = 1 << self.num_layer_vars();
 
@@ -40,12 +178,9 @@ impl<F: PrimeField> Layer<F> {
         let mut mul_i_evals = vec![F::ZERO; num_boolean_hypercube_evals];
 
         for gate in &self.gates {
-            let postional_index = get_positional_index(
-                self.layer_index(),
-                gate.output,
-                gate.left_index,
-                gate.right_index,
-            );
+            let (left_index, right_index) = gate.binary_inputs();
+            let postional_index =
+                get_positional_index(self.layer_index(), gate.output, left_index, right_index);
 
             match gate.op {
                 Op::Add => add_i_evals[postional_index] = F::ONE,
@@ -58,6 +193,31 @@ impl<F: PrimeField> Layer<F> {
             MultilinearPolynomial::new(mul_i_evals),
         )
     }
+
+    // same selectors as `add_i_and_mul_i_polynomials`, but only the wired (F::ONE) entries
+    // are stored instead of the full 2^num_layer_vars evaluation table
+    pub fn add_i_and_mul_i_sparse(
+        &self,
+    ) -> (SparseEvaluationPolynomial<F>, SparseEvaluationPolynomial<F>) {
+        let mut add_i_terms = vec![];
+        let mut mul_i_terms = vec![];
+
+        for gate in &self.gates {
+            let (left_index, right_index) = gate.binary_inputs();
+            let postional_index =
+                get_positional_index(self.layer_index(), gate.output, left_index, right_index);
+
+            match gate.op {
+                Op::Add => add_i_terms.push((F::ONE, postional_index)),
+                Op::Mul => mul_i_terms.push((F::ONE, postional_index)),
+            }
+        }
+
+        (
+            SparseEvaluationPolynomial::new(add_i_terms, self.num_layer_vars()),
+            SparseEvaluationPolynomial::new(mul_i_terms, self.num_layer_vars()),
+        )
+    }
 }
 
 pub fn get_positional_index(
@@ -78,6 +238,7 @@ pub fn get_positional_index(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ark_bls12_381::Fq;
 
     #[test]
     fn test_get_positional_index() {
@@ -88,4 +249,89 @@ mod tests {
         // binary 10011100 = decimal 156
         assert_eq!(idx, 156);
     }
+
+    #[test]
+    fn test_cached_selector_polynomials() {
+        let mut layer = Layer::<Fq>::new(vec![
+            Gate::binary(Op::Add, 0, 0, 1),
+            Gate::binary(Op::Mul, 1, 2, 3),
+        ]);
+
+        let first = layer.add_i_and_mul_i_polynomials();
+        let second = layer.add_i_and_mul_i_polynomials();
+
+        assert_eq!(first, second);
+
+        layer.gates[1].op = Op::Add;
+
+        let (add_i, mul_i) = layer.add_i_and_mul_i_polynomials();
+
+        assert_ne!(add_i, first.0);
+        assert!(
+            mul_i
+                .evals_slice()
+                .iter()
+                .all(|&eval| eval == Fq::from(0u64))
+        );
+    }
+
+    #[test]
+    fn test_sparse_selectors_match_dense() {
+        use ark_ff::UniformRand;
+
+        let mut rng = rand::thread_rng();
+        let layer = Layer::<Fq>::new(vec![
+            Gate::binary(Op::Add, 0, 0, 1),
+            Gate::binary(Op::Mul, 1, 2, 3),
+        ]);
+
+        let (add_i, mul_i) = layer.add_i_and_mul_i_polynomials();
+        let (sparse_add_i, sparse_mul_i) = layer.add_i_and_mul_i_sparse();
+
+        assert_eq!(sparse_add_i.to_dense(), add_i);
+        assert_eq!(sparse_mul_i.to_dense(), mul_i);
+
+        for _ in 0..10 {
+            let point: Vec<Fq> = (0..layer.num_layer_vars())
+                .map(|_| Fq::rand(&mut rng))
+                .collect();
+
+            assert_eq!(sparse_add_i.evaluate(&point), add_i.evaluate(&point));
+            assert_eq!(sparse_mul_i.evaluate(&point), mul_i.evaluate(&point));
+        }
+    }
+
+    #[test]
+    fn test_non_power_of_two_layer_selectors() {
+        let layer = Layer::<Fq>::new(vec![
+            Gate::binary(Op::Add, 0, 0, 1),
+            Gate::binary(Op::Mul, 1, 2, 3),
+            Gate::binary(Op::Add, 2, 4, 5),
+        ]);
+
+        // padded to 4 gates: 2 output bits, 3 bits for each input
+        assert_eq!(layer.layer_index(), 2);
+        assert_eq!(layer.num_layer_vars(), 8);
+
+        let (add_i, mul_i) = layer.add_i_and_mul_i_polynomials();
+
+        assert_eq!(add_i.evals_slice().len(), 1 << 8);
+        assert_eq!(mul_i.evals_slice().len(), 1 << 8);
+
+        let one = Fq::from(1u64);
+        let wired = |poly: &MultilinearPolynomial<Fq>| -> Vec<usize> {
+            (0..poly.evals_slice().len())
+                .filter(|&i| poly.evals_slice()[i] == one)
+                .collect()
+        };
+
+        assert_eq!(
+            wired(&add_i),
+            vec![
+                get_positional_index(2, 0, 0, 1),
+                get_positional_index(2, 2, 4, 5)
+            ]
+        );
+        assert_eq!(wired(&mul_i), vec![get_positional_index(2, 1, 2, 3)]);
+    }
 }
@@ -1,7 +1,9 @@
+pub mod builder;
 pub mod circuit;
 pub mod gate;
 pub mod layer;
 
+pub use builder::*;
 pub use circuit::*;
 pub use gate::*;
 pub use layer::*;
@@ -0,0 +1,35 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    RowCountMismatch { left: usize, right: usize },
+    ColumnCountMismatch { left: usize, right: usize },
+    InnerDimensionMismatch { left_cols: usize, right_rows: usize },
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::RowCountMismatch { left, right } => write!(
+                f,
+                "The two matrices must have the same number of rows, got {} and {}",
+                left, right
+            ),
+            MatrixError::ColumnCountMismatch { left, right } => write!(
+                f,
+                "The two matrices must have the same number of columns, got {} and {}",
+                left, right
+            ),
+            MatrixError::InnerDimensionMismatch {
+                left_cols,
+                right_rows,
+            } => write!(
+                f,
+                "Inner dimensions must match for multiplication, got {} columns and {} rows",
+                left_cols, right_rows
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
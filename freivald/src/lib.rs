@@ -1,34 +1,74 @@
 use ark_ff::PrimeField;
-use matrix::Matrix;
 
+pub mod error;
 mod matrix;
 
+pub use error::MatrixError;
+pub use matrix::Matrix;
+
 pub struct Freivald<F: PrimeField> {
-    x: Vec<F>,
+    // one probe vector per repetition, each built from an independent random r
+    probes: Vec<Vec<F>>,
 }
 
 impl<F: PrimeField> Freivald<F> {
     fn new(array_size: usize) -> Self {
+        Self::new_with_repetitions(array_size, 1)
+    }
+
+    // each repetition fails to catch a wrong product with probability at most
+    // (array_size - 1) / |F|, so k independent probes drive that down to its k-th power.
+    // one probe is plenty over large fields, k > 1 is meant for small ones.
+    pub fn new_with_repetitions(array_size: usize, k: usize) -> Self {
+        assert!(k > 0, "Freivald needs at least one repetition");
+
         // Generate random number
         // Populate vector with values r^i for i=0..matrix_size
-        // Return freivald value with this vector as its x value
+        // Repeat with a fresh r for each of the k probes
         let mut rng = rand::thread_rng();
-        let r = F::rand(&mut rng);
-        let x = (0..array_size).map(|i| r.pow([i as u64])).collect();
+        let probes = (0..k)
+            .map(|_| {
+                let r = F::rand(&mut rng);
+                (0..array_size).map(|i| r.pow([i as u64])).collect()
+            })
+            .collect();
+
+        Self { probes }
+    }
 
-        Self { x }
+    // the probe of the first repetition, which is the only one for `Freivald::new`
+    pub fn probe(&self) -> &[F] {
+        &self.probes[0]
     }
 
     pub fn verify(&self, matrix_a: Matrix<F>, matrix_b: Matrix<F>, supposed_ab: Matrix<F>) -> bool {
+        self.probes
+            .iter()
+            .all(|probe| Self::verify_with_x(&matrix_a, &matrix_b, &supposed_ab, probe))
+    }
+
+    // single check with a caller supplied probe, e.g. one derived from a Fiat-Shamir
+    // transcript instead of sampled locally
+    pub fn verify_with_x(
+        matrix_a: &Matrix<F>,
+        matrix_b: &Matrix<F>,
+        supposed_ab: &Matrix<F>,
+        x: &[F],
+    ) -> bool {
         assert!(
-            check_matrix_dimensions(&matrix_a, &matrix_b, &supposed_ab),
+            check_matrix_dimensions(matrix_a, matrix_b, supposed_ab),
             "Inner dimensions must match for multiplication"
         );
+        assert_eq!(
+            x.len(),
+            supposed_ab.ncols(),
+            "Probe length must match the number of columns of the product"
+        );
 
         // Check if a * b * x == c * x
-        let x = Matrix::new(vec![self.x.clone()]).transpose();
+        let x = Matrix::new(vec![x.to_vec()]).transpose();
 
-        matrix_a * (matrix_b * x.clone()) == &supposed_ab * &x
+        matrix_a * &(matrix_b * &x) == supposed_ab * &x
     }
 
     // utility function to not have to instantiate Freivalds if you just want to make one
@@ -60,6 +100,8 @@ pub fn check_matrix_dimensions<F: PrimeField>(
 mod tests {
     use super::*;
     use ark_bls12_381::Fq;
+    use sha3::Keccak256;
+    use transcript::Transcript;
 
     fn fq(n: u64) -> Fq {
         Fq::from(n)
@@ -93,4 +135,56 @@ mod tests {
         assert!(!freivald.verify(a.clone(), b.clone(), wrong_ab.clone()));
         assert!(!Freivald::verify_once(a, b, wrong_ab));
     }
+
+    #[test]
+    fn test_freivald_with_repetitions() {
+        let a = Matrix::new(vec![vec![fq(1), fq(2)], vec![fq(3), fq(4)]]);
+        let b = Matrix::new(vec![vec![fq(5), fq(6)], vec![fq(7), fq(8)]]);
+        let ab = Matrix::new(vec![vec![fq(19), fq(22)], vec![fq(43), fq(50)]]);
+        let wrong_ab = Matrix::new(vec![vec![fq(19), fq(22)], vec![fq(43), fq(51)]]);
+
+        let freivald = Freivald::new_with_repetitions(2, 3);
+
+        assert_eq!(freivald.probes.len(), 3);
+        assert!(freivald.verify(a.clone(), b.clone(), ab));
+        assert!(!freivald.verify(a, b, wrong_ab));
+    }
+
+    #[test]
+    #[should_panic(expected = "Freivald needs at least one repetition")]
+    fn test_freivald_zero_repetitions() {
+        let _ = Freivald::<Fq>::new_with_repetitions(2, 0);
+    }
+
+    #[test]
+    fn test_probe() {
+        let freivald = Freivald::<Fq>::new(3);
+        let r = freivald.probe()[1];
+
+        assert_eq!(freivald.probe(), &[fq(1), r, r * r]);
+    }
+
+    #[test]
+    fn test_verify_with_transcript_derived_x() {
+        let a_rows = vec![vec![fq(1), fq(2)], vec![fq(3), fq(4)]];
+        let b_rows = vec![vec![fq(5), fq(6)], vec![fq(7), fq(8)]];
+        let ab_rows = vec![vec![fq(19), fq(22)], vec![fq(43), fq(50)]];
+
+        let mut transcript = Transcript::<Fq, Keccak256>::new();
+
+        for element in a_rows.iter().chain(&b_rows).chain(&ab_rows).flatten() {
+            transcript.append_field_element(element);
+        }
+
+        let r = transcript.sample_field_element();
+        let x = vec![fq(1), r];
+
+        let a = Matrix::new(a_rows);
+        let b = Matrix::new(b_rows);
+        let ab = Matrix::new(ab_rows);
+        let wrong_ab = Matrix::new(vec![vec![fq(19), fq(22)], vec![fq(43), fq(51)]]);
+
+        assert!(Freivald::verify_with_x(&a, &b, &ab, &x));
+        assert!(!Freivald::verify_with_x(&a, &b, &wrong_ab, &x));
+    }
 }
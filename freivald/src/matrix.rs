@@ -1,5 +1,6 @@
+use crate::error::MatrixError;
 use ark_ff::PrimeField;
-use std::ops::{Add, Mul};
+use std::ops::{Add, Mul, Neg, Sub};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Matrix<F: PrimeField> {
@@ -11,6 +12,41 @@ impl<F: PrimeField> Matrix<F> {
         Matrix { rep }
     }
 
+    // rows of `data` laid out one after another, i.e. entry (i, j) is data[i * cols + j]
+    pub fn from_flat(data: Vec<F>, rows: usize, cols: usize) -> Self {
+        assert_eq!(
+            data.len(),
+            rows * cols,
+            "Flat data of length {} can't fill a {}x{} matrix",
+            data.len(),
+            rows,
+            cols
+        );
+
+        Matrix::new(
+            (0..rows)
+                .map(|i| data[i * cols..(i + 1) * cols].to_vec())
+                .collect(),
+        )
+    }
+
+    // inverse of `from_flat`, rows in order
+    pub fn to_flat(&self) -> Vec<F> {
+        self.rep.concat()
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let rep = (0..n)
+            .map(|i| {
+                let mut row = vec![F::ZERO; n];
+                row[i] = F::ONE;
+                row
+            })
+            .collect();
+
+        Matrix::new(rep)
+    }
+
     pub fn nrows(&self) -> usize {
         self.rep.len()
     }
@@ -40,17 +76,20 @@ impl<F: PrimeField> Matrix<F> {
         Matrix::new(transposed)
     }
 
-    pub fn add_matrices(&self, other: &Self) -> Self {
-        assert_eq!(
-            self.nrows(),
-            other.nrows(),
-            "The two matrices must have the same number of rows"
-        );
-        assert_eq!(
-            self.ncols(),
-            other.ncols(),
-            "The two matrices must have the same number of columns"
-        );
+    pub fn try_add(&self, other: &Self) -> Result<Self, MatrixError> {
+        if self.nrows() != other.nrows() {
+            return Err(MatrixError::RowCountMismatch {
+                left: self.nrows(),
+                right: other.nrows(),
+            });
+        }
+
+        if self.ncols() != other.ncols() {
+            return Err(MatrixError::ColumnCountMismatch {
+                left: self.ncols(),
+                right: other.ncols(),
+            });
+        }
 
         let new_rep = self
             .rep
@@ -59,15 +98,20 @@ impl<F: PrimeField> Matrix<F> {
             .map(|(row_a, row_b)| row_a.iter().zip(row_b).map(|(&a, &b)| a + b).collect())
             .collect();
 
-        Matrix::new(new_rep)
+        Ok(Matrix::new(new_rep))
     }
 
-    pub fn mul_matrices(&self, other: &Self) -> Self {
-        assert_eq!(
-            self.ncols(),
-            other.nrows(),
-            "Inner dimensions must match for multiplication"
-        );
+    pub fn add_matrices(&self, other: &Self) -> Self {
+        self.try_add(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_mul(&self, other: &Self) -> Result<Self, MatrixError> {
+        if self.ncols() != other.nrows() {
+            return Err(MatrixError::InnerDimensionMismatch {
+                left_cols: self.ncols(),
+                right_rows: other.nrows(),
+            });
+        }
 
         let mut new_rep = vec![vec![F::ZERO; other.ncols()]; self.nrows()];
         for i in 0..self.nrows() {
@@ -78,7 +122,36 @@ impl<F: PrimeField> Matrix<F> {
             }
         }
 
-        Matrix::new(new_rep)
+        Ok(Matrix::new(new_rep))
+    }
+
+    pub fn mul_matrices(&self, other: &Self) -> Self {
+        self.try_mul(other).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    // square and multiply from the most significant bit of exp down
+    pub fn pow(&self, exp: u64) -> Self {
+        assert_eq!(
+            self.nrows(),
+            self.ncols(),
+            "Only square matrices can be raised to a power"
+        );
+
+        if exp == 1 {
+            return self.clone();
+        }
+
+        let mut result = Matrix::identity(self.nrows());
+
+        for bit in (0..u64::BITS - exp.leading_zeros()).rev() {
+            result = result.mul_matrices(&result);
+
+            if (exp >> bit) & 1 == 1 {
+                result = result.mul_matrices(self);
+            }
+        }
+
+        result
     }
 }
 
@@ -114,6 +187,38 @@ impl<F: PrimeField> Mul for &Matrix<F> {
     }
 }
 
+impl<F: PrimeField> Neg for Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn neg(self) -> Self::Output {
+        self.scalar_mul(-F::ONE)
+    }
+}
+
+impl<F: PrimeField> Neg for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn neg(self) -> Self::Output {
+        self.scalar_mul(-F::ONE)
+    }
+}
+
+impl<F: PrimeField> Sub for Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.add_matrices(&-rhs)
+    }
+}
+
+impl<F: PrimeField> Sub for &Matrix<F> {
+    type Output = Matrix<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.add_matrices(&-rhs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +236,28 @@ mod tests {
         assert_eq!(m.ncols(), 3);
     }
 
+    #[test]
+    fn test_flat_round_trip() {
+        let data: Vec<Fq> = (1..=6).map(fq).collect();
+        let m = Matrix::from_flat(data.clone(), 2, 3);
+
+        assert_eq!(
+            m,
+            Matrix::new(vec![vec![fq(1), fq(2), fq(3)], vec![fq(4), fq(5), fq(6)]])
+        );
+        assert_eq!(m.to_flat(), data);
+        assert_eq!(
+            Matrix::from_flat(m.transpose().to_flat(), 3, 2),
+            m.transpose()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Flat data of length 5 can't fill a 2x3 matrix")]
+    fn test_from_flat_dimension_mismatch() {
+        let _ = Matrix::from_flat((1..=5).map(fq).collect(), 2, 3);
+    }
+
     #[test]
     fn test_addition() {
         let a = Matrix::new(vec![vec![fq(1), fq(2)], vec![fq(3), fq(4)]]);
@@ -170,6 +297,23 @@ mod tests {
         let _ = a + b;
     }
 
+    #[test]
+    fn test_try_add_dimension_errors() {
+        let a = Matrix::new(vec![vec![fq(1), fq(2)]]);
+        let b = Matrix::new(vec![vec![fq(1), fq(2)], vec![fq(3), fq(4)]]);
+        let c = Matrix::new(vec![vec![fq(1)]]);
+
+        assert_eq!(
+            a.try_add(&b),
+            Err(MatrixError::RowCountMismatch { left: 1, right: 2 })
+        );
+        assert_eq!(
+            a.try_add(&c),
+            Err(MatrixError::ColumnCountMismatch { left: 2, right: 1 })
+        );
+        assert_eq!(a.try_add(&a), Ok(&a + &a));
+    }
+
     #[test]
     fn test_scalar_multiplication() {
         let m = Matrix::new(vec![vec![fq(1), fq(2)], vec![fq(3), fq(4)]]);
@@ -195,4 +339,55 @@ mod tests {
 
         let _ = a * b;
     }
+
+    #[test]
+    fn test_try_mul_dimension_error() {
+        let a = Matrix::new(vec![vec![fq(1), fq(2)]]);
+
+        assert_eq!(
+            a.try_mul(&a),
+            Err(MatrixError::InnerDimensionMismatch {
+                left_cols: 2,
+                right_rows: 1
+            })
+        );
+        assert_eq!(a.try_mul(&a.transpose()), Ok(&a * &a.transpose()));
+    }
+
+    #[test]
+    fn test_pow() {
+        let m = Matrix::new(vec![vec![fq(1), fq(2)], vec![fq(3), fq(4)]]);
+
+        assert_eq!(m.pow(0), Matrix::identity(2));
+        assert_eq!(m.pow(1), m);
+        assert_eq!(m.pow(3), &(&m * &m) * &m);
+        assert_eq!(m.pow(10), &m.pow(5) * &m.pow(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only square matrices can be raised to a power")]
+    fn test_pow_non_square() {
+        let m = Matrix::new(vec![vec![fq(1), fq(2)]]);
+
+        let _ = m.pow(2);
+    }
+
+    #[test]
+    fn test_negation() {
+        let m = Matrix::new(vec![vec![fq(1), fq(2), fq(3)], vec![fq(4), fq(5), fq(6)]]);
+        let zero = Matrix::new(vec![vec![fq(0); 3]; 2]);
+
+        assert_eq!(-&m + m.clone(), zero);
+        assert_eq!(-(-m.clone()), m);
+    }
+
+    #[test]
+    fn test_subtraction() {
+        let a = Matrix::new(vec![vec![fq(6), fq(8)], vec![fq(10), fq(12)]]);
+        let b = Matrix::new(vec![vec![fq(5), fq(6)], vec![fq(7), fq(8)]]);
+        let expected = Matrix::new(vec![vec![fq(1), fq(2)], vec![fq(3), fq(4)]]);
+
+        assert_eq!(&a - &b, expected);
+        assert_eq!(a.clone() - a, Matrix::new(vec![vec![fq(0); 2]; 2]));
+    }
 }
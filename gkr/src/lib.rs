@@ -16,6 +16,67 @@ pub struct GKRProofResult<F: PrimeField> {
     pub wc_evals: Vec<F>,
 }
 
+// every GKR transcript starts from the circuit wiring, so a proof is bound to the circuit it
+// was produced for and can't be replayed against a rewired one with the same output
+pub fn circuit_transcript<F: PrimeField>(circuit: &Circuit<F>) -> Transcript<F, Keccak256> {
+    let mut transcript = Transcript::new();
+
+    transcript.append(&circuit.to_bytes());
+
+    transcript
+}
+
+// random linear combination of all output claims: the output layer's MLE is absorbed, r_0 is
+// sampled for every output bit and the initial claim is W_0(r_0). the output is padded to at
+// least two entries since layer 0 selectors always spend at least one bit on the output index.
+pub fn initial_output_claim<F: PrimeField>(
+    output: &[F],
+    transcript: &mut Transcript<F, Keccak256>,
+) -> (Vec<F>, F) {
+    let mut output_evals = output.to_vec();
+    output_evals.resize(output.len().next_power_of_two().max(2), F::ZERO);
+
+    let w_0 = MultilinearPolynomial::new(output_evals);
+
+    w_0.absorb_into(transcript);
+
+    let r_0 = transcript.sample_n_field_elements(w_0.n_vars());
+    let claim = w_0.evaluate(&r_0);
+
+    (r_0, claim)
+}
+
+// the claims W(r_b) and W(r_c) left by a layer's sumcheck are merged into the single claim
+// alpha·W(r_b) + beta·W(r_c) that the next layer's sumcheck starts from
+pub fn fold_claims<F: PrimeField>(alpha: F, beta: F, wb: F, wc: F) -> F {
+    alpha * wb + beta * wc
+}
+
+// the matching fold of a selector over (a, b, c): alpha·s(r_b, b, c) + beta·s(r_c, b, c),
+// i.e. the output variables fixed at r_b and at r_c, weighted like the claims in `fold_claims`
+pub fn fold_selector<F: PrimeField>(
+    selector: &MultilinearPolynomial<F>,
+    r_b: &[F],
+    r_c: &[F],
+    alpha: F,
+    beta: F,
+) -> MultilinearPolynomial<F> {
+    assert_eq!(
+        r_b.len(),
+        r_c.len(),
+        "r_b and r_c must fix the same number of output variables"
+    );
+
+    // fixing variable 0 over and over fixes the leading variables in order
+    let fix_output = |r: &[F]| {
+        selector.partial_evaluate_many_vars(&r.iter().map(|&x| (x, 0)).collect::<Vec<_>>())
+    };
+
+    fix_output(r_b)
+        .scalar_mul(alpha)
+        .tensor_add(&fix_output(r_c).scalar_mul(beta))
+}
+
 // pub fn prove<F: PrimeField>(circuit: &mut Circuit<F>, input: Vec<F>) -> GKRProofResult<F> {
 // let circuit_eval = circuit.evaluate(input);
 // let mut transcript: Transcript<F, Keccak256> = Transcript::new();
@@ -55,3 +116,208 @@ pub struct GKRProofResult<F: PrimeField> {
 
 // todo!()
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
+    use circuit::CircuitBuilder;
+    use polynomials::composed::ProductPolynomial;
+
+    fn fq(val: u64) -> Fq {
+        Fq::from(val)
+    }
+
+    #[test]
+    fn test_prover_and_verifier_folding_agree() {
+        let mut rng = rand::thread_rng();
+        let mut rand_vec = |n: usize| (0..n).map(|_| Fq::rand(&mut rng)).collect::<Vec<_>>();
+
+        // selector over 2 output bits and 2 bits per input
+        let selector = MultilinearPolynomial::new(rand_vec(1 << 6));
+        let w = MultilinearPolynomial::new(rand_vec(1 << 2));
+        let (r_b, r_c, bc) = (rand_vec(2), rand_vec(2), rand_vec(4));
+        let (alpha, beta) = (Fq::rand(&mut rng), Fq::rand(&mut rng));
+
+        // the prover folds the selector once and evaluates it at the sumcheck point, the
+        // verifier evaluates the selector at (r_b, b, c) and (r_c, b, c) and folds the results
+        let folded = fold_selector(&selector, &r_b, &r_c, alpha, beta);
+        let at = |r: &[Fq]| selector.evaluate(&[r, &bc[..]].concat());
+
+        assert_eq!(folded.n_vars(), 4);
+        assert_eq!(
+            folded.evaluate(&bc),
+            fold_claims(alpha, beta, at(&r_b), at(&r_c))
+        );
+
+        // folding W itself with every variable fixed is exactly the folded claim
+        assert_eq!(
+            fold_selector(&w, &r_b, &r_c, alpha, beta).evaluate(&[]),
+            fold_claims(alpha, beta, w.evaluate(&r_b), w.evaluate(&r_c))
+        );
+    }
+
+    #[test]
+    fn test_initial_output_claim_multiple_outputs() {
+        let mut circuit = CircuitBuilder::<Fq>::new()
+            .add_layer(vec![
+                (Op::Add, 0, 1),
+                (Op::Mul, 2, 3),
+                (Op::Add, 4, 5),
+                (Op::Mul, 6, 7),
+            ])
+            .build();
+
+        let output = circuit.evaluate((1..=8).map(fq).collect());
+        let mut transcript = Transcript::new();
+        let (r_0, claim) = initial_output_claim(&output, &mut transcript);
+
+        assert_eq!(r_0.len(), 2);
+        assert_eq!(claim, circuit.layer_mle(0).evaluate(&r_0));
+
+        let mut replayed = Transcript::<Fq, Keccak256>::new();
+        replayed.append(&circuit.layer_mle(0).to_bytes());
+
+        assert_eq!(replayed.sample_n_field_elements(2), r_0);
+    }
+
+    #[test]
+    fn test_initial_output_claim_single_output() {
+        let mut transcript = Transcript::<Fq, Keccak256>::new();
+        let (r_0, claim) = initial_output_claim(&[fq(15)], &mut transcript);
+
+        assert_eq!(r_0.len(), 1);
+        assert_eq!(claim, fq(15) * (fq(1) - r_0[0]));
+    }
+
+    #[test]
+    fn test_circuit_transcript_binds_wiring() {
+        // both circuits output [3, 12] on this input
+        let mut circuit = CircuitBuilder::<Fq>::new()
+            .add_layer(vec![(Op::Add, 0, 1), (Op::Mul, 2, 3)])
+            .build();
+        let mut rewired = CircuitBuilder::<Fq>::new()
+            .add_layer(vec![(Op::Add, 1, 0), (Op::Mul, 3, 2)])
+            .build();
+
+        let input = vec![fq(1), fq(2), fq(3), fq(4)];
+        let output = circuit.evaluate(input.clone());
+
+        assert_eq!(output, rewired.evaluate(input));
+
+        let (r_0, claim) = initial_output_claim(&output, &mut circuit_transcript(&circuit));
+        let (rewired_r_0, rewired_claim) =
+            initial_output_claim(&output, &mut circuit_transcript(&rewired));
+
+        assert_ne!(r_0, rewired_r_0);
+        assert_ne!(claim, rewired_claim);
+
+        // a layer 0 sumcheck proven against one circuit's transcript
+        let layer_polynomial = layer_0_polynomial(&circuit, &r_0);
+        let mut prover_transcript = circuit_transcript(&circuit);
+        initial_output_claim(&output, &mut prover_transcript);
+        let (round_polys, _) =
+            partial_prove(claim, layer_polynomial.clone(), &mut prover_transcript);
+
+        let verify_against = |verifier_circuit: &Circuit<Fq>| {
+            let mut transcript = circuit_transcript(verifier_circuit);
+            let (_, verifier_claim) = initial_output_claim(&output, &mut transcript);
+
+            partial_verify(
+                &mut transcript,
+                verifier_claim,
+                round_polys.clone(),
+                layer_polynomial.degree(),
+            )
+            .0
+        };
+
+        assert!(verify_against(&circuit));
+        assert!(!verify_against(&rewired));
+    }
+
+    // f(b, c) = add(r_0, b, c)·(W_1(b) + W_1(c)) + mul(r_0, b, c)·W_1(b)·W_1(c)
+    fn layer_0_polynomial(circuit: &Circuit<Fq>, r_0: &[Fq]) -> SumPolynomial<Fq> {
+        let (add_i, mul_i) = circuit.add_i_and_mul_i_polynomials(0);
+        let fix_output = |selector: &MultilinearPolynomial<Fq>| {
+            selector.partial_evaluate_many_vars(&r_0.iter().map(|&x| (x, 0)).collect::<Vec<_>>())
+        };
+        let w_1 = circuit.w_i_polynomial(1);
+        let one = MultilinearPolynomial::one(w_1.n_vars());
+        let (w_b, w_c) = (w_1.kronecker(&one), one.kronecker(&w_1));
+
+        SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![fix_output(&add_i), w_b.tensor_add(&w_c)]),
+            ProductPolynomial::new(vec![fix_output(&mul_i), w_b, w_c]),
+        ])
+    }
+}
+
+// field-op counts of the GKR building blocks, run over field_tracker's Ft instead of Fq
+#[cfg(all(test, feature = "track"))]
+mod track_tests {
+    use super::*;
+    use ark_bls12_381::Fq as ArkField;
+    use circuit::CircuitBuilder;
+    use field_tracker::{Ft, end_tscope, print_summary, start_tscope};
+    use polynomials::composed::ProductPolynomial;
+
+    type Fq = Ft!(ArkField);
+
+    #[test]
+    fn test_track_gkr_provers() {
+        let mut circuit = CircuitBuilder::<Fq>::new()
+            .add_layer(vec![
+                (Op::Mul, 0, 1),
+                (Op::Add, 2, 3),
+                (Op::Mul, 4, 5),
+                (Op::Add, 6, 7),
+            ])
+            .add_layer(vec![(Op::Add, 0, 1), (Op::Mul, 2, 3)])
+            .build();
+
+        start_tscope!("circuit evaluation");
+        let output = circuit.evaluate((1..=8).map(Fq::from).collect());
+        end_tscope!();
+
+        start_tscope!("output claim");
+        let mut transcript = circuit_transcript(&circuit);
+        let (r_0, claim) = initial_output_claim(&output, &mut transcript);
+        end_tscope!();
+
+        // f(b, c) = add(r_0, b, c)·(W_1(b) + W_1(c)) + mul(r_0, b, c)·W_1(b)·W_1(c)
+        start_tscope!("layer 0 sumcheck");
+        let (add_i, mul_i) = circuit.add_i_and_mul_i_polynomials(0);
+        let fix_output = |selector: &MultilinearPolynomial<Fq>| {
+            selector.partial_evaluate_many_vars(&r_0.iter().map(|&x| (x, 0)).collect::<Vec<_>>())
+        };
+        let w_1 = circuit.w_i_polynomial(1);
+        let one = MultilinearPolynomial::one(w_1.n_vars());
+        let (w_b, w_c) = (w_1.kronecker(&one), one.kronecker(&w_1));
+        let layer_polynomial = SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![fix_output(&add_i), w_b.tensor_add(&w_c)]),
+            ProductPolynomial::new(vec![fix_output(&mul_i), w_b, w_c]),
+        ]);
+
+        let (round_polys, challenges) =
+            partial_prove(claim, layer_polynomial.clone(), &mut transcript);
+        end_tscope!();
+
+        let mut verifier_transcript = circuit_transcript(&circuit);
+        initial_output_claim(&output, &mut verifier_transcript);
+
+        let (verified, final_claim, verifier_challenges) = partial_verify(
+            &mut verifier_transcript,
+            claim,
+            round_polys,
+            layer_polynomial.degree(),
+        );
+
+        assert!(verified);
+        assert_eq!(verifier_challenges, challenges);
+        assert_eq!(layer_polynomial.evaluate(&challenges), final_claim);
+
+        print_summary!();
+    }
+}
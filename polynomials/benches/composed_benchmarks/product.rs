@@ -0,0 +1,58 @@
+use ark_bls12_381::Fq;
+use ark_ff::UniformRand;
+use criterion::{Criterion, black_box};
+use polynomials::{composed::ProductPolynomial, multilinear::MultilinearPolynomial};
+use rand::thread_rng;
+
+fn sample_poly(num_vars: usize) -> MultilinearPolynomial<Fq> {
+    let mut rng = thread_rng();
+    let evals = (0..1 << num_vars).map(|_| Fq::rand(&mut rng)).collect();
+
+    MultilinearPolynomial::new(evals)
+}
+
+// selector-like factor: zero everywhere on the hypercube except for a single vertex
+fn sample_selector(num_vars: usize) -> MultilinearPolynomial<Fq> {
+    let mut evals = vec![Fq::from(0u64); 1 << num_vars];
+    evals[1] = Fq::from(1u64);
+
+    MultilinearPolynomial::new(evals)
+}
+
+pub fn product_polynomial_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("product polynomials");
+    let num_vars = 12;
+    let product = ProductPolynomial::new(vec![
+        sample_selector(num_vars),
+        sample_poly(num_vars),
+        sample_poly(num_vars),
+        sample_poly(num_vars),
+    ]);
+    let points: Vec<Vec<Fq>> = MultilinearPolynomial::boolean_points(num_vars)
+        .take(16)
+        .collect();
+
+    group.bench_function("evaluate zero-heavy factors on the hypercube", |b| {
+        b.iter(|| {
+            for point in &points {
+                black_box(product.evaluate(point));
+            }
+        });
+    });
+
+    group.bench_function("evaluate every factor on the hypercube", |b| {
+        b.iter(|| {
+            for point in &points {
+                black_box(
+                    product
+                        .polynomials
+                        .iter()
+                        .map(|poly| poly.evaluate(point))
+                        .product::<Fq>(),
+                );
+            }
+        });
+    });
+
+    group.finish();
+}
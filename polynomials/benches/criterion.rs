@@ -1,10 +1,15 @@
 use criterion::{Criterion, criterion_group, criterion_main};
+mod composed_benchmarks;
 mod multilinear_benchmarks;
 mod univariate_benchmarks;
 
+use composed_benchmarks::product::product_polynomial_benchmarks;
 use multilinear_benchmarks::{
     dense::dense_multilinear_polynomial_benchmarks,
-    evaluation::evaluation_form_multilinear_polynomial_benchmarks,
+    evaluation::{
+        evaluation_form_multilinear_polynomial_benchmarks, multilinear_evaluator_benchmarks,
+    },
+    partial_evaluation::partial_evaluation_strategy_benchmarks,
     sparse::sparse_multilinear_polynomial_benchmarks,
 };
 use univariate_benchmarks::{
@@ -14,6 +19,6 @@ use univariate_benchmarks::{
 criterion_group!(
     name = polynomials;
     config = Criterion::default().sample_size(10).configure_from_args();
-    targets = dense_multilinear_polynomial_benchmarks, sparse_multilinear_polynomial_benchmarks, dense_univariate_polynomial_benchmarks, sparse_univariate_polynomial_benchmarks, evaluation_form_multilinear_polynomial_benchmarks
+    targets = dense_multilinear_polynomial_benchmarks, sparse_multilinear_polynomial_benchmarks, dense_univariate_polynomial_benchmarks, sparse_univariate_polynomial_benchmarks, evaluation_form_multilinear_polynomial_benchmarks, multilinear_evaluator_benchmarks, partial_evaluation_strategy_benchmarks, product_polynomial_benchmarks
 );
 criterion_main!(polynomials);
@@ -1,2 +1,3 @@
+pub mod composed_benchmarks;
 pub mod multilinear_benchmarks;
 pub mod univariate_benchmarks;
@@ -1,7 +1,7 @@
 use ark_bls12_381::Fq;
 use ark_ff::UniformRand;
 use criterion::{Criterion, black_box};
-use polynomials::multilinear::evaluation::MultilinearPolynomial;
+use polynomials::multilinear::{MultilinearPolynomialEvaluator, evaluation::MultilinearPolynomial};
 use rand::thread_rng;
 
 fn sample_poly(num_vars: usize) -> MultilinearPolynomial<Fq> {
@@ -64,3 +64,42 @@ pub fn evaluation_form_multilinear_polynomial_benchmarks(c: &mut Criterion) {
 
     group.finish();
 }
+
+pub fn multilinear_evaluator_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multilinear evaluator with shared prefixes");
+    let num_vars = 18;
+    let num_points = 8;
+    let poly = sample_poly(num_vars);
+    let mut rng = thread_rng();
+
+    // points only differ in their last two coordinates
+    let prefix: Vec<Fq> = (0..num_vars - 2).map(|_| Fq::rand(&mut rng)).collect();
+    let points: Vec<Vec<Fq>> = (0..num_points)
+        .map(|_| {
+            let mut point = prefix.clone();
+            point.push(Fq::rand(&mut rng));
+            point.push(Fq::rand(&mut rng));
+            point
+        })
+        .collect();
+
+    group.bench_function("independent evaluations", |b| {
+        b.iter(|| {
+            for point in &points {
+                black_box(poly.evaluate(point));
+            }
+        });
+    });
+
+    group.bench_function("evaluator", |b| {
+        b.iter(|| {
+            let mut evaluator = MultilinearPolynomialEvaluator::from(poly.clone());
+
+            for point in &points {
+                black_box(evaluator.evaluate(point));
+            }
+        });
+    });
+
+    group.finish();
+}
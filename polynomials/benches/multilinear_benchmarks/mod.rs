@@ -1,3 +1,4 @@
 pub mod dense;
 pub mod evaluation;
+pub mod partial_evaluation;
 pub mod sparse;
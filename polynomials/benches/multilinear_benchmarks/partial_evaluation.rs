@@ -0,0 +1,68 @@
+use ark_bls12_381::Fq;
+use ark_ff::{Field, UniformRand};
+use criterion::{BenchmarkId, Criterion, black_box};
+use rand::thread_rng;
+
+// both strategies fix variable `var_index` of an `n_vars`-variable evaluation vector, i.e.
+// fold every pair (evals[i], evals[i + stride]) that differs only in that variable
+
+// impl 1, the one `MultilinearPolynomial::partial_evaluate_many_vars` uses: walk the
+// evaluations chunk by chunk and pair the two halves of every chunk
+fn fold_chunked<F: Field>(evals: &[F], n_vars: usize, var_index: usize, value: F) -> Vec<F> {
+    let stride = 1 << (n_vars - var_index - 1);
+    let mut new_evals = Vec::with_capacity(evals.len() / 2);
+
+    for chunk in evals.chunks(stride << 1) {
+        for i in 0..stride {
+            new_evals.push(chunk[i] + (chunk[i + stride] - chunk[i]) * value);
+        }
+    }
+
+    new_evals
+}
+
+// impl 2: a single index walk that jumps over the upper half of every chunk
+fn fold_strided<F: Field>(evals: &[F], n_vars: usize, var_index: usize, value: F) -> Vec<F> {
+    let stride = 1 << (n_vars - var_index - 1);
+    let chunk_size = stride << 1;
+    let mut new_evals = Vec::with_capacity(evals.len() / 2);
+    let mut i = 0;
+
+    while i < evals.len() {
+        new_evals.push(evals[i] + (evals[i + stride] - evals[i]) * value);
+        i += 1;
+
+        if i % chunk_size == stride {
+            i += stride;
+        }
+    }
+
+    new_evals
+}
+
+pub fn partial_evaluation_strategy_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multilinear partial evaluation strategies");
+    let mut rng = thread_rng();
+
+    for n_vars in (8..=22).step_by(2) {
+        let evals: Vec<Fq> = (0..1 << n_vars).map(|_| Fq::rand(&mut rng)).collect();
+        let value = Fq::rand(&mut rng);
+
+        // the first variable is what sumcheck and `evaluate` fix, the last one has stride 1
+        for (label, var_index) in [("first variable", 0), ("last variable", n_vars - 1)] {
+            group.bench_with_input(
+                BenchmarkId::new(format!("chunked, {}", label), n_vars),
+                &evals,
+                |b, evals| b.iter(|| black_box(fold_chunked(evals, n_vars, var_index, value))),
+            );
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("strided, {}", label), n_vars),
+                &evals,
+                |b, evals| b.iter(|| black_box(fold_strided(evals, n_vars, var_index, value))),
+            );
+        }
+    }
+
+    group.finish();
+}
@@ -65,4 +65,50 @@ pub fn dense_univariate_polynomial_benchmarks(c: &mut Criterion) {
             black_box(DenseUnivariatePolynomial::interpolate(&xs, &ys))
         });
     });
+
+    group.bench_function("polynomial barycentric interpolation", |b| {
+        b.iter(|| {
+            let mut xs = Vec::with_capacity(100);
+            let mut ys = Vec::with_capacity(100);
+            for _ in 0..100 {
+                xs.push(Fq::rand(&mut rng));
+                ys.push(Fq::rand(&mut rng));
+            }
+            black_box(DenseUnivariatePolynomial::interpolate_barycentric(&xs, &ys))
+        });
+    });
+
+    group.bench_function("interpolant evaluation at a single point", |b| {
+        b.iter(|| {
+            let mut xs = Vec::with_capacity(100);
+            let mut ys = Vec::with_capacity(100);
+            for _ in 0..100 {
+                xs.push(Fq::rand(&mut rng));
+                ys.push(Fq::rand(&mut rng));
+            }
+            let z = Fq::rand(&mut rng);
+            black_box(DenseUnivariatePolynomial::evaluate_interpolant_at(
+                &xs, &ys, z,
+            ))
+        });
+    });
+
+    let xs: Vec<Fq> = (0..100).map(|_| Fq::rand(&mut rng)).collect();
+    let yss: Vec<Vec<Fq>> = (0..10)
+        .map(|_| (0..100).map(|_| Fq::rand(&mut rng)).collect())
+        .collect();
+
+    group.bench_function("repeated interpolation of 10 y-vectors", |b| {
+        b.iter(|| {
+            black_box(
+                yss.iter()
+                    .map(|ys| DenseUnivariatePolynomial::interpolate(&xs, ys))
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+
+    group.bench_function("batch interpolation of 10 y-vectors", |b| {
+        b.iter(|| black_box(DenseUnivariatePolynomial::interpolate_many(&xs, &yss)))
+    });
 }
@@ -2,6 +2,34 @@ use ark_bls12_381::Fq;
 use ark_ff::UniformRand;
 use criterion::{Criterion, black_box};
 use polynomials::univariate::sparse::SparseUnivariatePolynomial;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// counts heap allocations so the interpolation benches can report allocations next to time
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(f());
+
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
 
 fn sample_poly() -> SparseUnivariatePolynomial<Fq> {
     let mut rng = rand::thread_rng();
@@ -46,6 +74,13 @@ pub fn sparse_univariate_polynomial_benchmarks(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("polynomial evaluation with power table", |b| {
+        b.iter(|| {
+            let x = Fq::rand(&mut rng);
+            black_box(poly.evaluate_with_powers(x));
+        })
+    });
+
     group.bench_function("polynomial addition", |b| {
         b.iter(|| black_box(&poly + &poly_2))
     });
@@ -54,6 +89,31 @@ pub fn sparse_univariate_polynomial_benchmarks(c: &mut Criterion) {
         b.iter(|| black_box(&poly * &poly_2))
     });
 
+    // interpolating 100 points ends in a sum of 100 scaled basis polynomials. `interpolate`
+    // accumulates it in place, the reference builds a new term vector for every `+`
+    let xs: Vec<Fq> = (0..100).map(|_| Fq::rand(&mut rng)).collect();
+    let ys: Vec<Fq> = (0..100).map(|_| Fq::rand(&mut rng)).collect();
+    let interpolate_by_addition = || {
+        xs.iter().zip(&ys).fold(
+            SparseUnivariatePolynomial::new(vec![(Fq::from(0u64), 0)]),
+            |acc, (&x, &y)| &acc + &SparseUnivariatePolynomial::basis(x, &xs).scalar_mul(y),
+        )
+    };
+
+    println!(
+        "interpolation of 100 points: {} allocations summed by addition, {} summed in place",
+        count_allocations(interpolate_by_addition),
+        count_allocations(|| SparseUnivariatePolynomial::interpolate(&xs, &ys))
+    );
+
+    group.bench_function("interpolation of 100 points, summed by addition", |b| {
+        b.iter(|| black_box(interpolate_by_addition()))
+    });
+
+    group.bench_function("interpolation of 100 points, summed in place", |b| {
+        b.iter(|| black_box(SparseUnivariatePolynomial::interpolate(&xs, &ys)))
+    });
+
     group.bench_function("polynomial interpolation", |b| {
         b.iter(|| {
             let mut xs = Vec::with_capacity(100);
@@ -1,5 +1,11 @@
 use crate::multilinear::MultilinearPolynomial;
 use ark_ff::PrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use sha3::{Digest, digest::FixedOutputReset};
+use transcript::Transcript;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ProductPolynomial<F: PrimeField> {
@@ -8,6 +14,11 @@ pub struct ProductPolynomial<F: PrimeField> {
 
 impl<F: PrimeField> ProductPolynomial<F> {
     pub fn new(polynomials: Vec<MultilinearPolynomial<F>>) -> Self {
+        assert!(
+            !polynomials.is_empty(),
+            "Product polynomial must contain at least one polynomial"
+        );
+
         let n_vars = polynomials[0].n_vars();
 
         assert!(
@@ -26,11 +37,22 @@ impl<F: PrimeField> ProductPolynomial<F> {
         self.polynomials.len()
     }
 
+    // stops at the first factor that evaluates to zero, which is common for selector-like
+    // factors evaluated on the hypercube
     pub fn evaluate(&self, points: &[F]) -> F {
-        self.polynomials
-            .iter()
-            .map(|poly| poly.evaluate(points))
-            .product()
+        let mut result = F::ONE;
+
+        for poly in &self.polynomials {
+            let eval = poly.evaluate(points);
+
+            if eval.is_zero() {
+                return F::ZERO;
+            }
+
+            result *= eval;
+        }
+
+        result
     }
 
     pub fn partial_evaluate_many_vars(&self, points: &[(F, usize)]) -> Self {
@@ -62,15 +84,104 @@ impl<F: PrimeField> ProductPolynomial<F> {
     }
 
     pub fn reduce(&self) -> Vec<F> {
-        self.element_wise_mul().evals_slice().to_vec()
+        self.element_wise_mul().into_evals()
+    }
+
+    // value of a fully reduced product, i.e. once every variable has been fixed
+    pub fn into_scalar(self) -> F {
+        assert_eq!(
+            self.n_vars(),
+            0,
+            "All variables must be fixed before reading off the scalar value"
+        );
+
+        self.polynomials
+            .iter()
+            .map(|poly| poly.evals_slice()[0])
+            .product()
+    }
+
+    // unlike `reduce` this also works for a single factor
+    pub fn sum_over_hypercube(&self) -> F {
+        (0..1 << self.n_vars())
+            .map(|i| {
+                self.polynomials
+                    .iter()
+                    .map(|poly| poly.evals_slice()[i])
+                    .product::<F>()
+            })
+            .sum()
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes_with_endianness(false)
+    }
+
+    pub fn to_bytes_with_endianness(&self, little_endian: bool) -> Vec<u8> {
         self.polynomials
             .iter()
-            .flat_map(|poly| poly.to_bytes())
+            .flat_map(|poly| poly.to_bytes_with_endianness(little_endian))
             .collect()
     }
+
+    pub fn absorb_into<H: Clone + Digest + FixedOutputReset>(
+        &self,
+        transcript: &mut Transcript<F, H>,
+    ) {
+        transcript.append(&self.to_bytes());
+    }
+}
+
+// hand-written so deserialization enforces what `new` does: at least one factor, all over
+// the same variables
+fn has_consistent_factors<F: PrimeField>(polynomials: &[MultilinearPolynomial<F>]) -> bool {
+    polynomials.first().is_some_and(|first| {
+        polynomials
+            .iter()
+            .all(|poly| poly.n_vars() == first.n_vars())
+    })
+}
+
+impl<F: PrimeField> CanonicalSerialize for ProductPolynomial<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.polynomials.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.polynomials.serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> Valid for ProductPolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if !has_consistent_factors(&self.polynomials) {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.polynomials.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for ProductPolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        // the factors are checked once, through `check`, and only when asked to
+        let polynomials = Vec::deserialize_with_mode(reader, compress, Validate::No)?;
+        let result = Self { polynomials };
+
+        if let Validate::Yes = validate {
+            result.check()?;
+        }
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
@@ -108,6 +219,12 @@ mod tests {
         ProductPolynomial::new(vec![p1, p2]);
     }
 
+    #[test]
+    #[should_panic(expected = "Product polynomial must contain at least one polynomial")]
+    fn test_new_empty() {
+        ProductPolynomial::<Fq>::new(vec![]);
+    }
+
     #[test]
     fn test_evaluate() {
         let p1 = create_multilinear_poly(vec![1, 2, 3, 4]);
@@ -160,6 +277,24 @@ mod tests {
         product.element_wise_mul(); // Should panic
     }
 
+    #[test]
+    fn test_into_scalar() {
+        let poly1 = create_multilinear_poly(vec![1, 2, 3, 4]);
+        let poly2 = create_multilinear_poly(vec![5, 6, 7, 8]);
+        let product_poly = ProductPolynomial::new(vec![poly1, poly2]);
+        let points = [fq(3), fq(5)];
+
+        let reduced = product_poly.partial_evaluate_many_vars(&[(points[0], 0), (points[1], 1)]);
+
+        assert_eq!(reduced.into_scalar(), product_poly.evaluate(&points));
+    }
+
+    #[test]
+    #[should_panic(expected = "All variables must be fixed before reading off the scalar value")]
+    fn test_into_scalar_with_free_variables() {
+        ProductPolynomial::new(vec![create_multilinear_poly(vec![1, 2])]).into_scalar();
+    }
+
     #[test]
     fn test_to_bytes() {
         let p1 = create_multilinear_poly(vec![1, 2, 3, 4]);
@@ -169,4 +304,61 @@ mod tests {
 
         assert_eq!(pp.to_bytes(), expected_bytes);
     }
+
+    #[test]
+    fn test_deserialize_rejects_inconsistent_factors() {
+        let mut mismatched = vec![];
+        vec![
+            create_multilinear_poly(vec![1, 2, 3, 4]),
+            create_multilinear_poly(vec![1, 2]),
+        ]
+        .serialize_compressed(&mut mismatched)
+        .unwrap();
+
+        assert!(ProductPolynomial::<Fq>::deserialize_compressed(&mismatched[..]).is_err());
+        // without validation the bytes are taken as they are
+        assert!(ProductPolynomial::<Fq>::deserialize_compressed_unchecked(&mismatched[..]).is_ok());
+
+        let mut empty = vec![];
+        Vec::<MultilinearPolynomial<Fq>>::new()
+            .serialize_compressed(&mut empty)
+            .unwrap();
+
+        assert!(ProductPolynomial::<Fq>::deserialize_compressed(&empty[..]).is_err());
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let pp = ProductPolynomial::new(vec![
+            create_multilinear_poly(vec![1, 2, 3, 4]),
+            create_multilinear_poly(vec![5, 6, 7, 8]),
+        ]);
+        let mut bytes = vec![];
+        pp.serialize_compressed(&mut bytes).unwrap();
+
+        assert_eq!(
+            ProductPolynomial::deserialize_compressed(&bytes[..]).unwrap(),
+            pp
+        );
+    }
+
+    #[test]
+    fn test_evaluate_with_zero_factor() {
+        let selector = create_multilinear_poly(vec![0, 0, 0, 1]);
+        let p2 = create_multilinear_poly(vec![5, 6, 7, 8]);
+        let pp = ProductPolynomial::new(vec![selector.clone(), p2.clone()]);
+
+        for point in MultilinearPolynomial::<Fq>::boolean_points(2) {
+            let expected = selector.evaluate(&point) * p2.evaluate(&point);
+
+            assert_eq!(pp.evaluate(&point), expected);
+        }
+
+        let point = vec![fq(3), fq(7)];
+
+        assert_eq!(
+            pp.evaluate(&point),
+            selector.evaluate(&point) * p2.evaluate(&point)
+        );
+    }
 }
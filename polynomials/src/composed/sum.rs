@@ -1,14 +1,26 @@
 use super::product::ProductPolynomial;
-use crate::multilinear::MultilinearPolynomial;
+use crate::multilinear::{MultilinearPolynomial, SparseEvaluationPolynomial};
 use ark_ff::PrimeField;
-
-#[derive(Debug, Clone)]
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use sha3::{Digest, digest::FixedOutputReset};
+use std::ops::Sub;
+use transcript::Transcript;
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct SumPolynomial<F: PrimeField> {
     product_polynomials: Vec<ProductPolynomial<F>>,
 }
 
 impl<F: PrimeField> SumPolynomial<F> {
     pub fn new(product_polynomials: Vec<ProductPolynomial<F>>) -> Self {
+        assert!(
+            !product_polynomials.is_empty(),
+            "Sum polynomial must contain at least one product polynomial"
+        );
+
         let n_vars = product_polynomials[0].polynomials[0].n_vars();
 
         assert!(
@@ -24,12 +36,72 @@ impl<F: PrimeField> SumPolynomial<F> {
         }
     }
 
+    // GKR layer polynomial f(b, c) = add_i(r, b, c)·(W(b) + W(c)) + mul_i(r, b, c)·W(b)·W(c).
+    // The selectors are over (a, b, c) with a as the high bits of the index, so each wired
+    // gate is scattered into the (b, c) table with weight eq(r, a). This never builds the
+    // dense 2^|a,b,c| selector tables, only the 2^|b,c| tables the sum polynomial is made of.
+    pub fn from_sparse_selectors(
+        r: &[F],
+        add_i: &SparseEvaluationPolynomial<F>,
+        mul_i: &SparseEvaluationPolynomial<F>,
+        w_b: &MultilinearPolynomial<F>,
+        w_c: &MultilinearPolynomial<F>,
+    ) -> Self {
+        let bc_n_vars = w_b.n_vars() + w_c.n_vars();
+
+        assert!(
+            add_i.n_vars() == r.len() + bc_n_vars && mul_i.n_vars() == r.len() + bc_n_vars,
+            "Selectors must be over the variables of r, W_b and W_c"
+        );
+
+        // eq(r, a) for every a, with the first variable as the most significant bit
+        let eq_r = r.iter().fold(vec![F::ONE], |evals, &r_i| {
+            evals
+                .iter()
+                .flat_map(|&eval| [eval * (F::ONE - r_i), eval * r_i])
+                .collect()
+        });
+
+        let fix_a = |selector: &SparseEvaluationPolynomial<F>| {
+            let mut evals = vec![F::ZERO; 1 << bc_n_vars];
+
+            for &(value, index) in selector.terms_slice() {
+                evals[index & ((1 << bc_n_vars) - 1)] += value * eq_r[index >> bc_n_vars];
+            }
+
+            MultilinearPolynomial::new(evals)
+        };
+
+        let w_b_plus_w_c = w_b
+            .evals_slice()
+            .iter()
+            .flat_map(|&b| w_c.evals_slice().iter().map(move |&c| b + c))
+            .collect();
+
+        Self::new(vec![
+            ProductPolynomial::new(vec![fix_a(add_i), MultilinearPolynomial::new(w_b_plus_w_c)]),
+            ProductPolynomial::new(vec![fix_a(mul_i), w_b.kronecker(w_c)]),
+        ])
+    }
+
     pub fn n_vars(&self) -> usize {
         self.product_polynomials[0].n_vars()
     }
 
+    // products may have different numbers of factors, the sum has the largest degree
     pub fn degree(&self) -> usize {
-        self.product_polynomials[0].degree()
+        self.product_polynomials
+            .iter()
+            .map(ProductPolynomial::degree)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn sum_over_hypercube(&self) -> F {
+        self.product_polynomials
+            .iter()
+            .map(ProductPolynomial::sum_over_hypercube)
+            .sum()
     }
 
     pub fn evaluate(&self, points: &[F]) -> F {
@@ -53,6 +125,31 @@ impl<F: PrimeField> SumPolynomial<F> {
         self.partial_evaluate_many_vars(&[(point, var_index)])
     }
 
+    // fixes variables 0..values.len() to values, e.g. r_b then r_c in a GKR reduction.
+    // partial_evaluate_many_vars fixes the highest index first, so the original indices
+    // stay valid throughout.
+    pub fn partial_evaluate_prefix(&self, values: &[F]) -> Self {
+        let points: Vec<(F, usize)> = values
+            .iter()
+            .enumerate()
+            .map(|(var_index, &value)| (value, var_index))
+            .collect();
+
+        self.partial_evaluate_many_vars(&points)
+    }
+
+    // fixes the prefix like `partial_evaluate_prefix`. a full point yields the value as Ok,
+    // a shorter prefix hands the reduced polynomial back as Err for the caller to continue
+    pub fn evaluate_or_reduce(&self, points: &[F]) -> Result<F, SumPolynomial<F>> {
+        let reduced = self.partial_evaluate_prefix(points);
+
+        if points.len() == self.n_vars() {
+            Ok(reduced.into_scalar())
+        } else {
+            Err(reduced)
+        }
+    }
+
     pub fn element_wise_add(&self) -> MultilinearPolynomial<F> {
         assert!(
             self.product_polynomials.len() > 1,
@@ -69,15 +166,118 @@ impl<F: PrimeField> SumPolynomial<F> {
     }
 
     pub fn reduce(&self) -> Vec<F> {
-        self.element_wise_add().evals_slice().to_vec()
+        self.element_wise_add().into_evals()
+    }
+
+    // for each x, fixes the first variable to x and sums the result over the remaining
+    // hypercube, i.e. evaluates the sumcheck round polynomial at every x
+    pub fn evaluate_at_scalars(&self, xs: &[F]) -> Vec<F> {
+        xs.iter()
+            .map(|&x| self.partial_evaluate(x, 0).sum_over_hypercube())
+            .collect()
+    }
+
+    // value of a fully reduced sum, i.e. once every variable has been fixed
+    pub fn into_scalar(self) -> F {
+        self.product_polynomials
+            .into_iter()
+            .map(ProductPolynomial::into_scalar)
+            .sum()
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes_with_endianness(false)
+    }
+
+    pub fn to_bytes_with_endianness(&self, little_endian: bool) -> Vec<u8> {
         self.product_polynomials
             .iter()
-            .flat_map(|prod_poly| prod_poly.to_bytes())
+            .flat_map(|prod_poly| prod_poly.to_bytes_with_endianness(little_endian))
             .collect()
     }
+
+    pub fn absorb_into<H: Clone + Digest + FixedOutputReset>(
+        &self,
+        transcript: &mut Transcript<F, H>,
+    ) {
+        transcript.append(&self.to_bytes());
+    }
+}
+
+// a - b keeps the product/sum structure: b's products are appended with their first factor
+// negated, so no products are ever multiplied out
+impl<F: PrimeField> Sub for &SumPolynomial<F> {
+    type Output = SumPolynomial<F>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let negated = rhs.product_polynomials.iter().map(|product| {
+            let mut polynomials = product.polynomials.clone();
+            polynomials[0] = polynomials[0].scalar_mul(-F::ONE);
+
+            ProductPolynomial::new(polynomials)
+        });
+
+        SumPolynomial::new(
+            self.product_polynomials
+                .iter()
+                .cloned()
+                .chain(negated)
+                .collect(),
+        )
+    }
+}
+
+// at least one product, all over the same variables, as `new` requires
+fn has_consistent_products<F: PrimeField>(product_polynomials: &[ProductPolynomial<F>]) -> bool {
+    product_polynomials.first().is_some_and(|first| {
+        product_polynomials
+            .iter()
+            .all(|prod_poly| prod_poly.n_vars() == first.n_vars())
+    })
+}
+
+impl<F: PrimeField> CanonicalSerialize for SumPolynomial<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.product_polynomials
+            .serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.product_polynomials.serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> Valid for SumPolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if !has_consistent_products(&self.product_polynomials) {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.product_polynomials.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for SumPolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let product_polynomials = Vec::deserialize_with_mode(reader, compress, Validate::No)?;
+        let result = Self {
+            product_polynomials,
+        };
+
+        if let Validate::Yes = validate {
+            result.check()?;
+        }
+
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
@@ -85,6 +285,7 @@ mod tests {
     use super::*;
     use crate::multilinear::MultilinearPolynomial;
     use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
 
     fn fq(val: u64) -> Fq {
         Fq::from(val)
@@ -103,6 +304,35 @@ mod tests {
         ProductPolynomial::new(multilinears)
     }
 
+    #[test]
+    fn test_sub() {
+        let mut rng = rand::thread_rng();
+        let mut rand_poly =
+            || MultilinearPolynomial::new((0..8).map(|_| Fq::rand(&mut rng)).collect());
+
+        let a = SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![rand_poly(), rand_poly()]),
+            ProductPolynomial::new(vec![rand_poly(), rand_poly()]),
+        ]);
+        let b = SumPolynomial::new(vec![ProductPolynomial::new(vec![
+            rand_poly(),
+            rand_poly(),
+            rand_poly(),
+        ])]);
+        let difference = a.sub(&b);
+
+        for _ in 0..8 {
+            let point: Vec<Fq> = (0..3).map(|_| Fq::rand(&mut rng)).collect();
+
+            assert_eq!(
+                difference.evaluate(&point),
+                a.evaluate(&point) - b.evaluate(&point)
+            );
+        }
+
+        assert!((&a - &a).reduce().iter().all(|&eval| eval == fq(0)));
+    }
+
     #[test]
     fn test_new_valid_sum_poly() {
         let prod1 = create_product_poly(&[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
@@ -123,6 +353,24 @@ mod tests {
         SumPolynomial::new(vec![prod1, prod2]);
     }
 
+    #[test]
+    fn test_degree_is_the_largest_product_degree() {
+        let prod1 = create_product_poly(&[&[1, 2, 3, 4]]);
+        let prod2 = create_product_poly(&[&[5, 6, 7, 8], &[1, 1, 2, 2], &[3, 1, 4, 1]]);
+
+        assert_eq!(
+            SumPolynomial::new(vec![prod1.clone(), prod2.clone()]).degree(),
+            3
+        );
+        assert_eq!(SumPolynomial::new(vec![prod2, prod1]).degree(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Sum polynomial must contain at least one product polynomial")]
+    fn test_new_empty() {
+        SumPolynomial::<Fq>::new(vec![]);
+    }
+
     #[test]
     fn test_evaluate_sum_poly() {
         let prod1 = create_product_poly(&[&[1, 2, 3, 4]]); // degree 1
@@ -178,6 +426,18 @@ mod tests {
         sum_poly.element_wise_add();
     }
 
+    #[test]
+    fn test_into_scalar() {
+        let prod1 = create_product_poly(&[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+        let prod2 = create_product_poly(&[&[9, 10, 11, 12], &[13, 14, 15, 16]]);
+        let sum_poly = SumPolynomial::new(vec![prod1, prod2]);
+        let points = [fq(2), fq(9)];
+
+        let reduced = sum_poly.partial_evaluate_prefix(&points);
+
+        assert_eq!(reduced.into_scalar(), sum_poly.evaluate(&points));
+    }
+
     #[test]
     fn test_to_bytes_sum_poly() {
         let prod1 = create_product_poly(&[&[1, 2, 3, 4]]);
@@ -192,4 +452,138 @@ mod tests {
 
         assert_eq!(sum_poly.to_bytes(), expected);
     }
+
+    #[test]
+    fn test_deserialize_rejects_mismatched_vars() {
+        let mut mismatched = vec![];
+        vec![
+            create_product_poly(&[&[1, 2, 3, 4]]),
+            create_product_poly(&[&[1, 2, 3, 4, 5, 6, 7, 8]]),
+        ]
+        .serialize_compressed(&mut mismatched)
+        .unwrap();
+
+        assert!(SumPolynomial::<Fq>::deserialize_compressed(&mismatched[..]).is_err());
+        assert!(SumPolynomial::<Fq>::deserialize_compressed_unchecked(&mismatched[..]).is_ok());
+
+        let mut empty = vec![];
+        Vec::<ProductPolynomial<Fq>>::new()
+            .serialize_compressed(&mut empty)
+            .unwrap();
+
+        assert!(SumPolynomial::<Fq>::deserialize_compressed(&empty[..]).is_err());
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let prod1 = create_product_poly(&[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+        let prod2 = create_product_poly(&[&[9, 10, 11, 12], &[13, 14, 15, 16]]);
+        let sum_poly = SumPolynomial::new(vec![prod1, prod2]);
+        let mut bytes = vec![];
+        sum_poly.serialize_compressed(&mut bytes).unwrap();
+
+        assert_eq!(
+            SumPolynomial::<Fq>::deserialize_compressed(&bytes[..]).unwrap(),
+            sum_poly
+        );
+    }
+
+    #[test]
+    fn test_evaluate_at_scalars() {
+        let prod1 = create_product_poly(&[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+        let prod2 = create_product_poly(&[&[9, 10, 11, 12], &[13, 14, 15, 16]]);
+        let sum_poly = SumPolynomial::new(vec![prod1, prod2]);
+        let xs = vec![fq(0), fq(1), fq(2)];
+
+        let expected: Vec<Fq> = xs
+            .iter()
+            .map(|&x| sum_poly.evaluate(&[x, fq(0)]) + sum_poly.evaluate(&[x, fq(1)]))
+            .collect();
+
+        assert_eq!(sum_poly.evaluate_at_scalars(&xs), expected);
+    }
+
+    #[test]
+    fn test_equality() {
+        let sum_poly = || {
+            SumPolynomial::new(vec![
+                create_product_poly(&[&[1, 2, 3, 4], &[5, 6, 7, 8]]),
+                create_product_poly(&[&[9, 10, 11, 12], &[13, 14, 15, 16]]),
+            ])
+        };
+        let other = SumPolynomial::new(vec![
+            create_product_poly(&[&[1, 2, 3, 4], &[5, 6, 7, 8]]),
+            create_product_poly(&[&[9, 10, 11, 12], &[13, 14, 15, 17]]),
+        ]);
+
+        assert_eq!(sum_poly(), sum_poly());
+        assert_ne!(sum_poly(), other);
+        assert_eq!(
+            sum_poly().partial_evaluate(fq(3), 0),
+            sum_poly().partial_evaluate(fq(3), 0)
+        );
+    }
+
+    #[test]
+    fn test_from_sparse_selectors() {
+        let mut rng = rand::thread_rng();
+        let rand_evals =
+            |n: usize, rng: &mut rand::rngs::ThreadRng| (0..n).map(|_| Fq::rand(rng)).collect();
+
+        // 1 output bit, 2 bits per input
+        let add_i =
+            SparseEvaluationPolynomial::new(vec![(fq(1), 0b0_00_01), (fq(1), 0b1_10_11)], 5);
+        let mul_i = SparseEvaluationPolynomial::new(vec![(fq(1), 0b0_10_11)], 5);
+        let w_b = MultilinearPolynomial::new(rand_evals(4, &mut rng));
+        let w_c = w_b.clone();
+        let r = vec![Fq::rand(&mut rng)];
+
+        let sum_poly = SumPolynomial::from_sparse_selectors(&r, &add_i, &mul_i, &w_b, &w_c);
+
+        assert_eq!(sum_poly.n_vars(), 4);
+        assert_eq!(sum_poly.degree(), 2);
+
+        let (add_i, mul_i) = (add_i.to_dense(), mul_i.to_dense());
+
+        for _ in 0..10 {
+            let bc: Vec<Fq> = rand_evals(4, &mut rng);
+            let rbc: Vec<Fq> = r.iter().chain(&bc).copied().collect();
+            let (w_b_eval, w_c_eval) = (w_b.evaluate(&bc[..2]), w_c.evaluate(&bc[2..]));
+
+            let expected = add_i.evaluate(&rbc) * (w_b_eval + w_c_eval)
+                + mul_i.evaluate(&rbc) * w_b_eval * w_c_eval;
+
+            assert_eq!(sum_poly.evaluate(&bc), expected);
+        }
+    }
+
+    #[test]
+    fn test_partial_evaluate_prefix() {
+        let prod1 = create_product_poly(&[&[1, 2, 3, 4, 5, 6, 7, 8], &[8, 7, 6, 5, 4, 3, 2, 1]]);
+        let prod2 = create_product_poly(&[&[2, 0, 1, 3, 0, 4, 9, 1], &[1, 1, 2, 3, 5, 8, 13, 21]]);
+        let sum_poly = SumPolynomial::new(vec![prod1, prod2]);
+
+        let expected = sum_poly
+            .partial_evaluate(fq(5), 0)
+            .partial_evaluate(fq(7), 0);
+
+        assert_eq!(sum_poly.partial_evaluate_prefix(&[fq(5), fq(7)]), expected);
+        assert_eq!(sum_poly.partial_evaluate_prefix(&[]), sum_poly);
+    }
+
+    #[test]
+    fn test_evaluate_or_reduce() {
+        let prod1 = create_product_poly(&[&[1, 2, 3, 4], &[5, 6, 7, 8]]);
+        let prod2 = create_product_poly(&[&[9, 10, 11, 12], &[13, 14, 15, 16]]);
+        let sum_poly = SumPolynomial::new(vec![prod1, prod2]);
+
+        assert_eq!(
+            sum_poly.evaluate_or_reduce(&[fq(2), fq(3)]),
+            Ok(sum_poly.evaluate(&[fq(2), fq(3)]))
+        );
+        assert_eq!(
+            sum_poly.evaluate_or_reduce(&[fq(2)]),
+            Err(sum_poly.partial_evaluate(fq(2), 0))
+        );
+    }
 }
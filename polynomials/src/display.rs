@@ -0,0 +1,69 @@
+use ark_ff::PrimeField;
+use std::fmt;
+
+// field elements above (p - 1) / 2 are rendered as negatives, so -1 shows up as `- 1`
+// rather than as p - 1
+fn is_negative<F: PrimeField>(coeff: &F) -> bool {
+    coeff.into_bigint() > F::MODULUS_MINUS_ONE_DIV_TWO
+}
+
+// writes `terms` as a sum, skipping zero coefficients and hiding unit coefficients in front
+// of non-constant monomials. an empty monomial string denotes the constant term.
+pub(crate) fn write_terms<F: PrimeField>(
+    f: &mut fmt::Formatter<'_>,
+    terms: impl IntoIterator<Item = (F, String)>,
+) -> fmt::Result {
+    let mut is_first = true;
+
+    for (coeff, monomial) in terms {
+        if coeff.is_zero() {
+            continue;
+        }
+
+        let (sign, abs) = if is_negative(&coeff) {
+            ("-", -coeff)
+        } else {
+            ("+", coeff)
+        };
+
+        match (is_first, sign) {
+            (true, "-") => write!(f, "-")?,
+            (true, _) => {}
+            (false, _) => write!(f, " {} ", sign)?,
+        }
+
+        if monomial.is_empty() {
+            write!(f, "{}", abs)?;
+        } else if abs.is_one() {
+            write!(f, "{}", monomial)?;
+        } else {
+            write!(f, "{}{}", abs, monomial)?;
+        }
+
+        is_first = false;
+    }
+
+    if is_first {
+        write!(f, "0")?;
+    }
+
+    Ok(())
+}
+
+// renders a univariate power of x, e.g. `x^3`, `x` or the empty constant monomial
+pub(crate) fn univariate_monomial(exp: usize) -> String {
+    match exp {
+        0 => String::new(),
+        1 => "x".to_string(),
+        _ => format!("x^{}", exp),
+    }
+}
+
+// renders a multilinear monomial index as the product of the variables whose bit is set,
+// with bit i standing for x_i, e.g. 0b101 -> `x0x2`
+pub(crate) fn multilinear_monomial(monomial_index: usize, n_vars: usize) -> String {
+    (0..n_vars)
+        .filter(|i| monomial_index & (1 << i) != 0)
+        .map(|i| format!("x{}", i))
+        .collect()
+}
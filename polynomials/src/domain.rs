@@ -0,0 +1,48 @@
+use ark_ff::FftField;
+
+// the 2^k-th roots of unity ordered as w^0, w^1, ..., w^(2^k - 1) for a primitive 2^k-th root w
+pub fn radix2_domain<F: FftField>(k: usize) -> Vec<F> {
+    let size = 1u64 << k;
+    let generator = F::get_root_of_unity(size).unwrap_or_else(|| {
+        panic!(
+            "Field has no multiplicative subgroup of size 2^{} (two-adicity is {})",
+            k,
+            F::TWO_ADICITY
+        )
+    });
+
+    let mut domain = Vec::with_capacity(size as usize);
+    let mut current = F::ONE;
+
+    for _ in 0..size {
+        domain.push(current);
+        current *= generator;
+    }
+
+    domain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::{Fq, Fr};
+    use ark_ff::Field;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_radix2_domain() {
+        let k = 4;
+        let domain = radix2_domain::<Fr>(k);
+
+        assert_eq!(domain.len(), 1 << k);
+        assert_eq!(domain.iter().collect::<HashSet<_>>().len(), 1 << k);
+        assert!(domain.iter().all(|x| x.pow([1u64 << k]) == Fr::ONE));
+    }
+
+    #[test]
+    #[should_panic(expected = "Field has no multiplicative subgroup of size 2^2")]
+    fn test_radix2_domain_too_large() {
+        // the bls12-381 base field only has two-adicity 1
+        radix2_domain::<Fq>(2);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    composed::{ProductPolynomial, SumPolynomial},
+    multilinear::MultilinearPolynomial,
+};
+use ark_ff::PrimeField;
+use sha3::{Digest, digest::FixedOutputReset};
+use transcript::Transcript;
+
+// common evaluation interface so protocol code (e.g. a sumcheck prover) can be written once
+// over any of the multilinear-based polynomial types
+pub trait Evaluable<F: PrimeField> {
+    fn n_vars(&self) -> usize;
+
+    fn evaluate(&self, point: &[F]) -> F;
+
+    fn partial_evaluate(&self, value: F, var: usize) -> Self;
+
+    // highest degree of any single variable, e.g. the degree of a sumcheck round polynomial
+    fn max_degree(&self) -> usize;
+
+    fn sum_over_hypercube(&self) -> F;
+
+    fn absorb_into<H: Clone + Digest + FixedOutputReset>(&self, transcript: &mut Transcript<F, H>);
+}
+
+impl<F: PrimeField> Evaluable<F> for MultilinearPolynomial<F> {
+    fn n_vars(&self) -> usize {
+        self.n_vars()
+    }
+
+    fn evaluate(&self, point: &[F]) -> F {
+        self.evaluate(point)
+    }
+
+    fn partial_evaluate(&self, value: F, var: usize) -> Self {
+        self.partial_evaluate(value, var)
+    }
+
+    fn max_degree(&self) -> usize {
+        1
+    }
+
+    fn sum_over_hypercube(&self) -> F {
+        self.evals_slice().iter().sum()
+    }
+
+    fn absorb_into<H: Clone + Digest + FixedOutputReset>(&self, transcript: &mut Transcript<F, H>) {
+        self.absorb_into(transcript)
+    }
+}
+
+impl<F: PrimeField> Evaluable<F> for ProductPolynomial<F> {
+    fn n_vars(&self) -> usize {
+        self.n_vars()
+    }
+
+    fn evaluate(&self, point: &[F]) -> F {
+        self.evaluate(point)
+    }
+
+    fn partial_evaluate(&self, value: F, var: usize) -> Self {
+        self.partial_evaluate(value, var)
+    }
+
+    fn max_degree(&self) -> usize {
+        self.degree()
+    }
+
+    fn sum_over_hypercube(&self) -> F {
+        self.sum_over_hypercube()
+    }
+
+    fn absorb_into<H: Clone + Digest + FixedOutputReset>(&self, transcript: &mut Transcript<F, H>) {
+        self.absorb_into(transcript)
+    }
+}
+
+impl<F: PrimeField> Evaluable<F> for SumPolynomial<F> {
+    fn n_vars(&self) -> usize {
+        self.n_vars()
+    }
+
+    fn evaluate(&self, point: &[F]) -> F {
+        self.evaluate(point)
+    }
+
+    fn partial_evaluate(&self, value: F, var: usize) -> Self {
+        self.partial_evaluate(value, var)
+    }
+
+    fn max_degree(&self) -> usize {
+        self.degree()
+    }
+
+    fn sum_over_hypercube(&self) -> F {
+        self.sum_over_hypercube()
+    }
+
+    fn absorb_into<H: Clone + Digest + FixedOutputReset>(&self, transcript: &mut Transcript<F, H>) {
+        self.absorb_into(transcript)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
+
+    // fixes the first variable, then evaluates the rest, only through the trait
+    fn evaluate_in_two_steps<F: PrimeField, P: Evaluable<F>>(poly: &P, point: &[F]) -> F {
+        Evaluable::evaluate(&Evaluable::partial_evaluate(poly, point[0], 0), &point[1..])
+    }
+
+    fn random_poly(n_vars: usize) -> MultilinearPolynomial<Fq> {
+        let mut rng = rand::thread_rng();
+
+        MultilinearPolynomial::new((0..1 << n_vars).map(|_| Fq::rand(&mut rng)).collect())
+    }
+
+    #[test]
+    fn test_generic_evaluation_matches_concrete() {
+        let mut rng = rand::thread_rng();
+        let point: Vec<Fq> = (0..3).map(|_| Fq::rand(&mut rng)).collect();
+
+        let multilinear = random_poly(3);
+        let product = ProductPolynomial::new(vec![random_poly(3), random_poly(3)]);
+        let sum = SumPolynomial::new(vec![product.clone(), product.clone()]);
+
+        assert_eq!(Evaluable::n_vars(&multilinear), 3);
+        assert_eq!(Evaluable::n_vars(&product), 3);
+        assert_eq!(Evaluable::n_vars(&sum), 3);
+
+        assert_eq!(
+            evaluate_in_two_steps(&multilinear, &point),
+            multilinear.evaluate(&point)
+        );
+        assert_eq!(
+            evaluate_in_two_steps(&product, &point),
+            product.evaluate(&point)
+        );
+        assert_eq!(evaluate_in_two_steps(&sum, &point), sum.evaluate(&point));
+    }
+
+    #[test]
+    fn test_generic_hypercube_sum_and_degree() {
+        let multilinear = random_poly(3);
+        let product = ProductPolynomial::new(vec![random_poly(3), random_poly(3)]);
+        let single_factor = ProductPolynomial::new(vec![multilinear.clone()]);
+        let sum = SumPolynomial::new(vec![
+            product.clone(),
+            ProductPolynomial::new(vec![random_poly(3), random_poly(3), random_poly(3)]),
+        ]);
+
+        assert_eq!(Evaluable::max_degree(&multilinear), 1);
+        assert_eq!(Evaluable::max_degree(&product), 2);
+        assert_eq!(Evaluable::max_degree(&sum), 3);
+
+        assert_eq!(
+            Evaluable::sum_over_hypercube(&multilinear),
+            multilinear.evals_slice().iter().sum::<Fq>()
+        );
+        assert_eq!(
+            Evaluable::sum_over_hypercube(&single_factor),
+            Evaluable::sum_over_hypercube(&multilinear)
+        );
+        assert_eq!(
+            Evaluable::sum_over_hypercube(&product),
+            product.reduce().iter().sum::<Fq>()
+        );
+        assert_eq!(
+            Evaluable::sum_over_hypercube(&sum),
+            sum.reduce().iter().sum::<Fq>()
+        );
+    }
+}
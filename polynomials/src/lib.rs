@@ -1,3 +1,13 @@
 pub mod composed;
+mod display;
+pub mod domain;
+pub mod evaluable;
+pub mod mle;
 pub mod multilinear;
+#[cfg(test)]
+mod test_helpers;
 pub mod univariate;
+pub mod util;
+
+pub use evaluable::Evaluable;
+pub use mle::{evaluate_mle_at, mle_of};
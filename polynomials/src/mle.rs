@@ -0,0 +1,73 @@
+use crate::multilinear::MultilinearPolynomial;
+use ark_ff::PrimeField;
+
+// multilinear extension of `values` over the hypercube, zero padded to a power of two
+pub fn mle_of<F: PrimeField>(values: &[F]) -> MultilinearPolynomial<F> {
+    MultilinearPolynomial::new_padded(values.to_vec())
+}
+
+// same as `mle_of(values).evaluate(point)` without building the padded table. values are folded
+// pairwise as they stream in, the last variable first since it's the lowest bit of the index,
+// so only one pending value per variable is ever kept.
+pub fn evaluate_mle_at<F: PrimeField>(values: &[F], point: &[F]) -> F {
+    let padded_len = values.len().next_power_of_two();
+    let n_vars = padded_len.ilog2() as usize;
+
+    assert_eq!(
+        point.len(),
+        n_vars,
+        "Number of points must match number of variables"
+    );
+
+    // (number of variables folded so far, partial evaluation)
+    let mut pending: Vec<(usize, F)> = Vec::with_capacity(n_vars + 1);
+
+    for i in 0..padded_len {
+        let mut current = (0, values.get(i).copied().unwrap_or(F::ZERO));
+
+        while let Some(&(level, left)) = pending.last() {
+            if level != current.0 {
+                break;
+            }
+
+            pending.pop();
+
+            let r = point[n_vars - level - 1];
+            current = (level + 1, left + (current.1 - left) * r);
+        }
+
+        pending.push(current);
+    }
+
+    pending[0].1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
+
+    #[test]
+    fn test_evaluate_mle_at_matches_mle_of() {
+        let mut rng = rand::thread_rng();
+
+        for len in [2, 5, 8, 13] {
+            let values: Vec<Fq> = (0..len).map(|_| Fq::rand(&mut rng)).collect();
+            let mle = mle_of(&values);
+            let point: Vec<Fq> = (0..mle.n_vars()).map(|_| Fq::rand(&mut rng)).collect();
+
+            assert_eq!(evaluate_mle_at(&values, &point), mle.evaluate(&point));
+        }
+    }
+
+    #[test]
+    fn test_single_value_mle_is_constant() {
+        let value = Fq::from(42u64);
+        let mle = mle_of(&[value]);
+
+        assert_eq!(mle.n_vars(), 0);
+        assert_eq!(mle.evaluate(&[]), value);
+        assert_eq!(evaluate_mle_at(&[value], &[]), value);
+    }
+}
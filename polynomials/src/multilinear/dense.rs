@@ -1,7 +1,10 @@
+use crate::display::{multilinear_monomial, write_terms};
 use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use std::fmt;
 use std::ops::{Add, Mul};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct DenseMultilinearPolynomial<F: PrimeField> {
     coefficients: Vec<F>,
     n_vars: usize,
@@ -14,6 +17,18 @@ impl<F: PrimeField> DenseMultilinearPolynomial<F> {
         Self::new_with_coefficients(coefficients, n_vars)
     }
 
+    pub fn zero(n_vars: usize) -> Self {
+        Self::new(n_vars)
+    }
+
+    // the constant monomial is coefficient 0
+    pub fn one(n_vars: usize) -> Self {
+        let mut coefficients = vec![F::ZERO; 1 << n_vars];
+        coefficients[0] = F::ONE;
+
+        Self::new_with_coefficients(coefficients, n_vars)
+    }
+
     pub fn new_with_coefficients(coefficients: Vec<F>, n_vars: usize) -> Self {
         Self {
             coefficients,
@@ -32,10 +47,21 @@ impl<F: PrimeField> DenseMultilinearPolynomial<F> {
         self.n_vars
     }
 
+    // coefficient i belongs to the monomial of the variables whose bits are set in i, so a
+    // repeated variable can't even be encoded. what can go wrong is the layout: exactly one
+    // coefficient per subset of the n_vars variables is needed for that reading to hold.
+    pub fn is_multilinear(&self) -> bool {
+        self.coefficients.len() == 1 << self.n_vars
+    }
+
     pub fn coefficients_slice(&self) -> &[F] {
         &self.coefficients
     }
 
+    pub fn into_coefficients(self) -> Vec<F> {
+        self.coefficients
+    }
+
     pub fn scalar_mul(&self, scalar: F) -> Self {
         let mut new_coeffs = Vec::with_capacity(self.coefficients.len());
 
@@ -70,6 +96,17 @@ impl<F: PrimeField> DenseMultilinearPolynomial<F> {
             .sum()
     }
 
+    // point[i] is the value of variable i, for the common case where points aren't reordered
+    pub fn evaluate_at(&self, point: &[F]) -> F {
+        let positional_point: Vec<(F, u8)> = point
+            .iter()
+            .enumerate()
+            .map(|(pos, &val)| (val, pos as u8))
+            .collect();
+
+        self.evaluate(&positional_point)
+    }
+
     pub fn partial_evaluate(&self, partial_terms: &[(F, usize)]) -> Self {
         let mut new_coeffs = vec![F::ZERO; 1 << (self.n_vars - partial_terms.len())];
 
@@ -254,15 +291,68 @@ impl<F: PrimeField> Mul for &DenseMultilinearPolynomial<F> {
     }
 }
 
+impl<F: PrimeField> Mul<F> for DenseMultilinearPolynomial<F> {
+    type Output = DenseMultilinearPolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &DenseMultilinearPolynomial<F> {
+    type Output = DenseMultilinearPolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl<F: PrimeField> fmt::Display for DenseMultilinearPolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_terms(
+            f,
+            self.coefficients
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(i, coeff)| (*coeff, multilinear_monomial(i, self.n_vars))),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::{assert_mul_by_scalar, assert_zero_and_one_are_identities};
     use ark_bls12_381::Fq;
 
     fn fq(val: u64) -> Fq {
         Fq::from(val)
     }
 
+    #[test]
+    fn test_zero_and_one_are_identities() {
+        // f(x, y) = 3xy + 2x + 1
+        let poly =
+            DenseMultilinearPolynomial::new_with_coefficients(vec![fq(1), fq(2), fq(0), fq(3)], 2);
+
+        assert_zero_and_one_are_identities(
+            &poly,
+            &DenseMultilinearPolynomial::zero(2),
+            &DenseMultilinearPolynomial::one(2),
+            |a, b| a + b,
+            |a, b| a * b,
+        );
+    }
+
+    #[test]
+    fn test_mul_by_scalar() {
+        let poly =
+            DenseMultilinearPolynomial::new_with_coefficients(vec![fq(1), fq(2), fq(0), fq(3)], 2);
+
+        assert_mul_by_scalar(&poly, fq(7), &poly.scalar_mul(fq(7)));
+    }
+
     #[test]
     fn test_scalar_mul() {
         // f(x, y) = 3xy + 2x + 1
@@ -323,7 +413,18 @@ mod tests {
             n_vars,
         );
 
-        assert_eq!(poly1 * poly2, expected);
+        let product = poly1 * poly2;
+
+        assert!(product.is_multilinear());
+        assert_eq!(product, expected);
+    }
+
+    #[test]
+    fn test_is_multilinear_flags_corrupted_layout() {
+        let poly = DenseMultilinearPolynomial::new_with_coefficients(vec![fq(1); 6], 3);
+
+        assert!(!poly.is_multilinear());
+        assert!(DenseMultilinearPolynomial::<Fq>::new(3).is_multilinear());
     }
 
     #[test]
@@ -342,6 +443,7 @@ mod tests {
         let point = vec![(fq(2), 0), (fq(5), 1)];
         // 3*2*5 + 2*2 + 4 = 30 + 4 + 4 = 38
         assert_eq!(poly.evaluate(&point), fq(38));
+        assert_eq!(poly.evaluate_at(&[fq(2), fq(5)]), fq(38));
     }
 
     #[test]
@@ -418,4 +520,35 @@ mod tests {
 
         assert_eq!(poly, expected_poly);
     }
+
+    #[test]
+    fn test_display() {
+        // f(x0, x1) = 3x0x1 + 2x0 + 1
+        let poly =
+            DenseMultilinearPolynomial::new_with_coefficients(vec![fq(1), fq(2), fq(0), fq(3)], 2);
+
+        assert_eq!(poly.to_string(), "3x0x1 + 2x0 + 1");
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let poly =
+            DenseMultilinearPolynomial::new_with_coefficients(vec![fq(1), fq(2), fq(0), fq(3)], 2);
+        let mut bytes = vec![];
+        poly.serialize_compressed(&mut bytes).unwrap();
+
+        assert_eq!(
+            DenseMultilinearPolynomial::deserialize_compressed(&bytes[..]).unwrap(),
+            poly
+        );
+    }
+
+    #[test]
+    fn test_into_coefficients() {
+        let poly =
+            DenseMultilinearPolynomial::new_with_coefficients(vec![fq(1), fq(2), fq(0), fq(3)], 2);
+        let coefficients = poly.coefficients_slice().to_vec();
+
+        assert_eq!(poly.into_coefficients(), coefficients);
+    }
 }
@@ -1,4 +1,13 @@
-use ark_ff::{BigInteger, PrimeField};
+use crate::util::{DecodeError, field_byte_size, field_from_bytes, field_to_bytes};
+use ark_ff::PrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use rand::Rng;
+use sha3::{Digest, digest::FixedOutputReset};
+use std::ops::Mul;
+use transcript::Transcript;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct MultilinearPolynomial<F: PrimeField> {
@@ -7,22 +16,104 @@ pub struct MultilinearPolynomial<F: PrimeField> {
 
 impl<F: PrimeField> MultilinearPolynomial<F> {
     pub fn new(evals: Vec<F>) -> Self {
+        let len = evals.len();
+
         assert!(
-            evals.len().is_power_of_two(),
-            "Number of evaluations must be a power of two"
+            len.is_power_of_two(),
+            "Number of evaluations must be a power of two, got {}, expected a power of two such as {}",
+            len,
+            match len {
+                0 => "1".to_string(),
+                _ => format!("{} or {}", 1 << len.ilog2(), len.next_power_of_two()),
+            }
         );
 
         Self { evals }
     }
 
+    // pads with zeros up to the next power of two, so e.g. 3 evaluations become a 2-variable
+    // polynomial whose last hypercube evaluation is zero. padding adds variables, the
+    // original evaluations stay at the low indices.
+    pub fn new_padded(mut evals: Vec<F>) -> Self {
+        evals.resize(evals.len().next_power_of_two(), F::ZERO);
+
+        Self::new(evals)
+    }
+
+    // zero polynomial over n_vars variables, to be filled in place through `evals_mut`
+    pub fn new_zero(n_vars: usize) -> Self {
+        Self::new(vec![F::ZERO; 1 << n_vars])
+    }
+
+    // same as `new_zero`, named for symmetry with `one`
+    pub fn zero(n_vars: usize) -> Self {
+        Self::new_zero(n_vars)
+    }
+
+    // the constant 1 takes the value 1 at every point of the hypercube
+    pub fn one(n_vars: usize) -> Self {
+        Self::new(vec![F::ONE; 1 << n_vars])
+    }
+
     pub fn n_vars(&self) -> usize {
         self.evals.len().ilog2() as usize
     }
 
+    pub fn len(&self) -> usize {
+        self.evals.len()
+    }
+
+    // always false, a polynomial has at least the one evaluation of a constant
+    pub fn is_empty(&self) -> bool {
+        self.evals.is_empty()
+    }
+
+    // vertices of {0,1}^n_vars in the same lex order as `evals`, so the first variable is
+    // the most significant bit of the evaluation index
+    pub fn boolean_points(n_vars: usize) -> impl Iterator<Item = Vec<F>> {
+        (0..1usize << n_vars).map(move |index| {
+            (0..n_vars)
+                .map(|i| {
+                    if index & (1 << (n_vars - i - 1)) != 0 {
+                        F::ONE
+                    } else {
+                        F::ZERO
+                    }
+                })
+                .collect()
+        })
+    }
+
+    // evaluates `f` at every hypercube vertex in lex order, the vertex being passed as its
+    // bits with the first variable first
+    pub fn from_fn<Func: Fn(&[u8]) -> F>(n_vars: usize, f: Func) -> Self {
+        let mut vertex = vec![0u8; n_vars];
+        let evals = (0..1usize << n_vars)
+            .map(|index| {
+                for (i, bit) in vertex.iter_mut().enumerate() {
+                    *bit = ((index >> (n_vars - i - 1)) & 1) as u8;
+                }
+
+                f(&vertex)
+            })
+            .collect();
+
+        Self::new(evals)
+    }
+
     pub fn evals_slice(&self) -> &[F] {
         &self.evals
     }
 
+    // a slice rather than the Vec, so the number of evaluations stays a power of two
+    pub fn evals_mut(&mut self) -> &mut [F] {
+        &mut self.evals
+    }
+
+    pub fn into_evals(self) -> Vec<F> {
+        self.evals
+    }
+
     pub fn scalar_mul(&self, scalar: F) -> Self {
         Self {
             evals: self.evals.iter().map(|&x| x * scalar).collect(),
@@ -40,6 +131,24 @@ impl<F: PrimeField> MultilinearPolynomial<F> {
             .evals[0]
     }
 
+    // like `evaluate`, but with each value tagged by the variable it is assigned to, so the
+    // assignment can come in any order
+    pub fn evaluate_assignment(&self, assignment: &[(F, usize)]) -> F {
+        let n_vars = self.n_vars();
+        let mut seen = vec![false; n_vars];
+
+        assert!(
+            assignment.len() == n_vars
+                && assignment
+                    .iter()
+                    .all(|&(_, var)| var < n_vars && !std::mem::replace(&mut seen[var], true)),
+            "Assignment must fix every one of the {} variables exactly once",
+            n_vars
+        );
+
+        self.partial_evaluate_many_vars(assignment).evals[0]
+    }
+
     pub fn partial_evaluate(&self, point: F, var_index: usize) -> Self {
         self.partial_evaluate_many_vars(&[(point, var_index)])
     }
@@ -79,8 +188,10 @@ impl<F: PrimeField> MultilinearPolynomial<F> {
             let chunk_size = stride << 1; // 2 chunks of size stride (stride << 1 = stride * 2)
             let mut new_evals = Vec::with_capacity(evals.len() / 2);
 
-            // impl 1
-            // this was faster in benchmarks even though it had one more loop
+            // this is the only fold: a single index walk that skips the upper half of every
+            // chunk visits the same (y1, y2) pairs in the same order, and the "partial
+            // evaluation strategies" bench keeps both around for comparing them before adding
+            // any size based dispatch
             for chunk in evals.chunks(chunk_size) {
                 for i in 0..stride {
                     let y1 = chunk[i];
@@ -98,28 +209,43 @@ impl<F: PrimeField> MultilinearPolynomial<F> {
                 }
             }
 
-            // impl 2
-            // let mut i = 0;
+            evals = new_evals;
+            current_n_vars -= 1;
+        }
 
-            // while i < evals.len() {
-            //     let y1 = evals[i];
-            //     let y2 = evals[i + stride];
+        MultilinearPolynomial::new(evals)
+    }
 
-            //     // linear interpolation: (1 - x) * a + x * b = a + (b - a) * x
-            //     new_evals.push(y1 + (y2 - y1) * value);
+    // variable i of the result is variable permutation[i] of self, i.e.
+    // result(x_0, .., x_{n-1}) = self(y) with y_{permutation[i]} = x_i
+    pub fn permute_variables(&self, permutation: &[usize]) -> Self {
+        let n_vars = self.n_vars();
+        let mut seen = vec![false; n_vars];
 
-            //     i += 1;
+        assert!(
+            permutation.len() == n_vars
+                && permutation
+                    .iter()
+                    .all(|&var| var < n_vars && !std::mem::replace(&mut seen[var], true)),
+            "Variable permutation must be a permutation of 0..{}",
+            n_vars
+        );
 
-            //     if i % chunk_size == stride {
-            //         i += stride;
-            //     }
-            // }
+        let evals = (0..self.evals.len())
+            .map(|index| {
+                let original_index = permutation.iter().enumerate().fold(0, |acc, (i, &var)| {
+                    if index & (1 << (n_vars - i - 1)) != 0 {
+                        acc | (1 << (n_vars - var - 1))
+                    } else {
+                        acc
+                    }
+                });
 
-            evals = new_evals;
-            current_n_vars -= 1;
-        }
+                self.evals[original_index]
+            })
+            .collect();
 
-        MultilinearPolynomial::new(evals)
+        Self { evals }
     }
 
     pub fn tensor_add(&self, other: &Self) -> Self {
@@ -156,24 +282,332 @@ impl<F: PrimeField> MultilinearPolynomial<F> {
         Self { evals }
     }
 
+    // true tensor product over n_vars(self) + n_vars(other) variables: self's variables come
+    // first (high bits of the index), other's last (low bits)
+    pub fn kronecker(&self, other: &Self) -> Self {
+        let evals = self
+            .evals
+            .iter()
+            .flat_map(|x| other.evals.iter().map(move |y| *x * *y))
+            .collect();
+
+        Self { evals }
+    }
+
+    // Schwartz–Zippel check: two distinct multilinear polynomials agree at a random point
+    // with probability at most n_vars / |F|
+    pub fn probabilistic_eq<R: Rng>(&self, other: &Self, rng: &mut R) -> bool {
+        if self.n_vars() != other.n_vars() {
+            return false;
+        }
+
+        let point: Vec<F> = (0..self.n_vars()).map(|_| F::rand(rng)).collect();
+
+        self.evaluate(&point) == other.evaluate(&point)
+    }
+
+    // compares the hypercube evaluations index by index, with the shorter side read as zero
+    // past its end. `new_padded` keeps the original evaluations at the low indices, so a
+    // padded polynomial agrees with its unpadded source
+    pub fn agrees_on_hypercube(&self, other: &Self) -> bool {
+        let (shorter, longer) = if self.evals.len() <= other.evals.len() {
+            (&self.evals, &other.evals)
+        } else {
+            (&other.evals, &self.evals)
+        };
+
+        longer[..shorter.len()] == shorter[..]
+            && longer[shorter.len()..].iter().all(|eval| eval.is_zero())
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        self.write_bytes(&mut bytes)
+            .expect("Writing into a Vec can't fail");
+
+        bytes
+    }
+
+    // `to_bytes` with every evaluation written in the given byte order, for verifiers that
+    // expect little-endian field elements. transcripts always absorb the big-endian form
+    pub fn to_bytes_with_endianness(&self, little_endian: bool) -> Vec<u8> {
         self.evals
             .iter()
-            .flat_map(|el| el.into_bigint().to_bytes_be())
+            .flat_map(|eval| field_to_bytes(eval, little_endian))
             .collect()
     }
+
+    // the `to_bytes` encoding streamed one evaluation at a time, so large polynomials don't
+    // need a second full-size buffer
+    pub fn write_bytes<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        for eval in &self.evals {
+            w.write_all(&field_to_bytes(eval, false))?;
+        }
+
+        Ok(())
+    }
+
+    // inverse of `to_bytes`: every evaluation takes as many bytes as the big-endian limbs of
+    // an `F::BigInt`, and values at or above the modulus are rejected rather than reduced
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::from_bytes_with_endianness(bytes, false)
+    }
+
+    // inverse of `to_bytes_with_endianness`
+    pub fn from_bytes_with_endianness(
+        bytes: &[u8],
+        little_endian: bool,
+    ) -> Result<Self, DecodeError> {
+        let element_size = field_byte_size::<F>();
+
+        if !bytes.len().is_multiple_of(element_size) {
+            return Err(DecodeError::LengthNotMultipleOfElementSize {
+                len: bytes.len(),
+                element_size,
+            });
+        }
+
+        let num_evals = bytes.len() / element_size;
+
+        if !num_evals.is_power_of_two() {
+            return Err(DecodeError::NotPowerOfTwo { num_evals });
+        }
+
+        let evals = bytes
+            .chunks(element_size)
+            .enumerate()
+            .map(|(index, chunk)| {
+                field_from_bytes(chunk, little_endian)
+                    .ok_or(DecodeError::NonCanonicalElement { index })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { evals })
+    }
+
+    // the one place the transcript encoding of this polynomial is fixed, so prover and
+    // verifier can't drift apart
+    pub fn absorb_into<H: Clone + Digest + FixedOutputReset>(
+        &self,
+        transcript: &mut Transcript<F, H>,
+    ) {
+        self.write_bytes(&mut UpdateWriter(|bytes: &[u8]| transcript.append(bytes)))
+            .expect("Absorbing into a transcript can't fail");
+    }
+
+    // hashes the same encoding as `to_bytes` without materializing it, so a transcript
+    // can absorb a fixed-size digest instead of every evaluation
+    pub fn commitment_digest<H: Digest>(&self) -> Vec<u8> {
+        let mut hasher = H::new();
+
+        self.write_bytes(&mut UpdateWriter(|bytes: &[u8]| hasher.update(bytes)))
+            .expect("Hashing can't fail");
+
+        hasher.finalize().to_vec()
+    }
+
+    pub fn merkle_root<H: Digest>(&self) -> Vec<u8> {
+        let layers = self.merkle_layers::<H>();
+
+        layers[layers.len() - 1][0].clone()
+    }
+
+    // sibling hashes from the leaf level up to (but excluding) the root
+    pub fn merkle_proof<H: Digest>(&self, index: usize) -> Vec<Vec<u8>> {
+        assert!(
+            index < self.evals.len(),
+            "Leaf index {} is out of range for {} evaluations",
+            index,
+            self.evals.len()
+        );
+
+        let layers = self.merkle_layers::<H>();
+        let mut position = index;
+
+        layers[..layers.len() - 1]
+            .iter()
+            .map(|layer| {
+                let sibling = layer[position ^ 1].clone();
+                position >>= 1;
+                sibling
+            })
+            .collect()
+    }
+
+    // the number of evaluations is a power of two, so every layer pairs up exactly
+    fn merkle_layers<H: Digest>(&self) -> Vec<Vec<Vec<u8>>> {
+        let mut layers = vec![
+            self.evals
+                .iter()
+                .map(merkle_leaf::<F, H>)
+                .collect::<Vec<_>>(),
+        ];
+
+        while layers[layers.len() - 1].len() > 1 {
+            let next = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| merkle_node::<H>(&pair[0], &pair[1]))
+                .collect();
+            layers.push(next);
+        }
+
+        layers
+    }
+}
+
+// streams `write_bytes` into a hasher or transcript, so absorbing a polynomial never builds
+// the full-size encoding
+struct UpdateWriter<U: FnMut(&[u8])>(U);
+
+impl<U: FnMut(&[u8])> std::io::Write for UpdateWriter<U> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        (self.0)(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// leaves and internal nodes get distinct prefixes so an internal node can't be passed off as a leaf
+fn merkle_leaf<F: PrimeField, H: Digest>(eval: &F) -> Vec<u8> {
+    let mut hasher = H::new();
+    hasher.update([0u8]);
+    hasher.update(field_to_bytes(eval, false));
+    hasher.finalize().to_vec()
+}
+
+fn merkle_node<H: Digest>(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut hasher = H::new();
+    hasher.update([1u8]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().to_vec()
+}
+
+pub fn verify_merkle_proof<F: PrimeField, H: Digest>(
+    root: &[u8],
+    index: usize,
+    leaf: F,
+    proof: &[Vec<u8>],
+) -> bool {
+    if proof.len() < usize::BITS as usize && index >> proof.len() != 0 {
+        return false;
+    }
+
+    let mut position = index;
+    let mut node = merkle_leaf::<F, H>(&leaf);
+
+    for sibling in proof {
+        node = if position & 1 == 0 {
+            merkle_node::<H>(&node, sibling)
+        } else {
+            merkle_node::<H>(sibling, &node)
+        };
+        position >>= 1;
+    }
+
+    node == root
+}
+
+impl<F: PrimeField> Mul<F> for MultilinearPolynomial<F> {
+    type Output = MultilinearPolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &MultilinearPolynomial<F> {
+    type Output = MultilinearPolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
+// hand-written so deserialization can't produce a polynomial with a non-power-of-two number
+// of evaluations, which `new` would have rejected
+impl<F: PrimeField> CanonicalSerialize for MultilinearPolynomial<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.evals.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.evals.serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> Valid for MultilinearPolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if !self.evals.len().is_power_of_two() {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.evals.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for MultilinearPolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let evals = Vec::<F>::deserialize_with_mode(reader, compress, validate)?;
+
+        if !evals.len().is_power_of_two() {
+            return Err(SerializationError::InvalidData);
+        }
+
+        Ok(Self { evals })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::{assert_mul_by_scalar, assert_zero_and_one_are_identities};
     use ark_bls12_381::Fq;
     use ark_ff::UniformRand;
+    use sha3::Keccak256;
 
     fn fq(x: u64) -> Fq {
         Fq::from(x)
     }
 
+    #[test]
+    fn test_zero_and_one_are_identities() {
+        let poly = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(4)]);
+        let point = [fq(5), fq(7)];
+
+        assert_eq!(MultilinearPolynomial::<Fq>::zero(2).evaluate(&point), fq(0));
+        assert_eq!(MultilinearPolynomial::<Fq>::one(2).evaluate(&point), fq(1));
+
+        // addition and multiplication in evaluation form are pointwise on the hypercube
+        assert_zero_and_one_are_identities(
+            &poly,
+            &MultilinearPolynomial::zero(2),
+            &MultilinearPolynomial::one(2),
+            |a, b| a.tensor_add(b),
+            |a, b| a.tensor_mul(b),
+        );
+    }
+
+    #[test]
+    fn test_mul_by_scalar() {
+        let poly = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(4)]);
+
+        assert_mul_by_scalar(&poly, fq(7), &poly.scalar_mul(fq(7)));
+    }
+
     #[test]
     fn test_evaluate() {
         // 00 -> 0
@@ -308,6 +742,18 @@ mod tests {
         let _ = MultilinearPolynomial::new(evals); // Should panic
     }
 
+    #[test]
+    #[should_panic(expected = "got 6, expected a power of two such as 4 or 8")]
+    fn test_new_invalid_length_reports_length() {
+        let _ = MultilinearPolynomial::new(vec![fq(0); 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "got 0, expected a power of two such as 1")]
+    fn test_new_empty() {
+        let _ = MultilinearPolynomial::<Fq>::new(vec![]);
+    }
+
     #[test]
     fn test_scalar_mul() {
         let evals = vec![fq(1), fq(2), fq(3), fq(4)];
@@ -412,4 +858,416 @@ mod tests {
         let poly2 = MultilinearPolynomial::new(vec![fq(5), fq(6)]);
         let _ = poly1.tensor_mul(&poly2);
     }
+
+    #[test]
+    fn test_from_bytes_round_trip() {
+        let poly = MultilinearPolynomial::new(vec![fq(1), fq(2), -fq(3), fq(4)]);
+
+        assert_eq!(
+            MultilinearPolynomial::from_bytes(&poly.to_bytes()),
+            Ok(poly)
+        );
+    }
+
+    #[test]
+    fn test_bytes_with_endianness_round_trip() {
+        let poly = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(4)]);
+        let big_endian = poly.to_bytes_with_endianness(false);
+        let little_endian = poly.to_bytes_with_endianness(true);
+
+        assert_eq!(big_endian, poly.to_bytes());
+        assert_ne!(big_endian, little_endian);
+        assert_eq!(
+            MultilinearPolynomial::from_bytes_with_endianness(&big_endian, false),
+            Ok(poly.clone())
+        );
+        assert_eq!(
+            MultilinearPolynomial::from_bytes_with_endianness(&little_endian, true),
+            Ok(poly.clone())
+        );
+        assert_ne!(
+            MultilinearPolynomial::from_bytes_with_endianness(&little_endian, false),
+            Ok(poly)
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_malformed_input() {
+        let bytes = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(4)]).to_bytes();
+
+        assert_eq!(
+            MultilinearPolynomial::<Fq>::from_bytes(&bytes[1..]),
+            Err(DecodeError::LengthNotMultipleOfElementSize {
+                len: 4 * 48 - 1,
+                element_size: 48
+            })
+        );
+        assert_eq!(
+            MultilinearPolynomial::<Fq>::from_bytes(&bytes[48..]),
+            Err(DecodeError::NotPowerOfTwo { num_evals: 3 })
+        );
+        assert_eq!(
+            MultilinearPolynomial::<Fq>::from_bytes(&[]),
+            Err(DecodeError::NotPowerOfTwo { num_evals: 0 })
+        );
+
+        // all ones is above the modulus
+        let mut out_of_range = bytes.clone();
+        out_of_range[48..96].fill(0xff);
+        assert_eq!(
+            MultilinearPolynomial::<Fq>::from_bytes(&out_of_range),
+            Err(DecodeError::NonCanonicalElement { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_commitment_digest() {
+        let poly = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(4)]);
+        let other = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(5)]);
+        let digest = poly.commitment_digest::<Keccak256>();
+
+        assert_eq!(digest, Keccak256::digest(poly.to_bytes()).to_vec());
+
+        // absorbing streams the same bytes as appending the full encoding
+        let mut streamed = Transcript::<Fq, Keccak256>::new();
+        let mut buffered = Transcript::<Fq, Keccak256>::new();
+
+        poly.absorb_into(&mut streamed);
+        buffered.append(&poly.to_bytes());
+
+        assert_eq!(
+            streamed.sample_field_element(),
+            buffered.sample_field_element()
+        );
+        assert_eq!(digest.len(), 32);
+        assert_ne!(digest, other.commitment_digest::<Keccak256>());
+    }
+
+    #[test]
+    fn test_evaluate_assignment() {
+        let poly = MultilinearPolynomial::new((1..=8).map(fq).collect());
+        let (a, b, c) = (fq(3), fq(5), fq(7));
+
+        assert_eq!(
+            poly.evaluate_assignment(&[(c, 2), (a, 0), (b, 1)]),
+            poly.evaluate(&[a, b, c])
+        );
+        assert_eq!(
+            poly.evaluate_assignment(&[(b, 1), (c, 2), (a, 0)]),
+            poly.evaluate(&[a, b, c])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Assignment must fix every one of the 3 variables exactly once")]
+    fn test_evaluate_assignment_repeated_variable() {
+        let poly = MultilinearPolynomial::new((1..=8).map(fq).collect());
+
+        poly.evaluate_assignment(&[(fq(3), 0), (fq(5), 0), (fq(7), 2)]);
+    }
+
+    #[test]
+    fn test_merkle_proof() {
+        let poly = MultilinearPolynomial::new((1..=8).map(fq).collect());
+        let root = poly.merkle_root::<Keccak256>();
+
+        for index in 0..8 {
+            let proof = poly.merkle_proof::<Keccak256>(index);
+            assert_eq!(proof.len(), 3);
+            assert!(verify_merkle_proof::<_, Keccak256>(
+                &root,
+                index,
+                poly.evals[index],
+                &proof
+            ));
+        }
+
+        let proof = poly.merkle_proof::<Keccak256>(5);
+        // tampered leaf
+        assert!(!verify_merkle_proof::<_, Keccak256>(
+            &root,
+            5,
+            fq(7),
+            &proof
+        ));
+        // right leaf claimed at the wrong position
+        assert!(!verify_merkle_proof::<_, Keccak256>(
+            &root,
+            4,
+            fq(6),
+            &proof
+        ));
+        assert!(!verify_merkle_proof::<_, Keccak256>(
+            &root,
+            13,
+            fq(6),
+            &proof
+        ));
+        // tampered path
+        let mut tampered = proof.clone();
+        tampered[1][0] ^= 1;
+        assert!(!verify_merkle_proof::<_, Keccak256>(
+            &root,
+            5,
+            fq(6),
+            &tampered
+        ));
+    }
+
+    #[test]
+    fn test_merkle_root_single_evaluation() {
+        let poly = MultilinearPolynomial::new(vec![fq(9)]);
+        let root = poly.merkle_root::<Keccak256>();
+
+        assert!(poly.merkle_proof::<Keccak256>(0).is_empty());
+        assert!(verify_merkle_proof::<_, Keccak256>(&root, 0, fq(9), &[]));
+        assert!(!verify_merkle_proof::<_, Keccak256>(&root, 0, fq(8), &[]));
+    }
+
+    #[test]
+    fn test_partial_evaluate_many_vars_order_independent() {
+        use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+        // every case derives its own rng from the case seed, so a failure can be replayed
+        // (and minimized) from the seed in the panic message alone
+        for seed in 0..64u64 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let n_vars = rng.gen_range(1..=6);
+            let evals: Vec<Fq> = (0..1 << n_vars).map(|_| Fq::rand(&mut rng)).collect();
+            let poly = MultilinearPolynomial::new(evals);
+
+            let mut var_indices: Vec<usize> = (0..n_vars).collect();
+            var_indices.shuffle(&mut rng);
+            var_indices.truncate(rng.gen_range(0..=n_vars));
+            var_indices.sort();
+
+            let ascending: Vec<(Fq, usize)> = var_indices
+                .iter()
+                .map(|&var_index| (Fq::rand(&mut rng), var_index))
+                .collect();
+            let mut shuffled = ascending.clone();
+            shuffled.shuffle(&mut rng);
+
+            // fixing one variable at a time from the highest index down never shifts the
+            // indices that are still to be fixed
+            let expected = ascending
+                .iter()
+                .rev()
+                .fold(poly.clone(), |acc, &(value, var_index)| {
+                    acc.partial_evaluate(value, var_index)
+                });
+
+            assert_eq!(
+                poly.partial_evaluate_many_vars(&shuffled),
+                poly.partial_evaluate_many_vars(&ascending),
+                "seed {seed}: fixing {shuffled:?} disagrees with ascending order"
+            );
+            assert_eq!(
+                poly.partial_evaluate_many_vars(&ascending),
+                expected,
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_probabilistic_eq() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let evals: Vec<Fq> = (0..1 << 8).map(|_| Fq::rand(&mut rng)).collect();
+        let poly = MultilinearPolynomial::new(evals.clone());
+
+        for _ in 0..10 {
+            assert!(poly.probabilistic_eq(&poly.clone(), &mut rng));
+        }
+
+        let mut tampered_evals = evals;
+        tampered_evals[17] += fq(1);
+        let tampered = MultilinearPolynomial::new(tampered_evals);
+
+        assert!(!poly.probabilistic_eq(&tampered, &mut rng));
+
+        let fewer_vars = MultilinearPolynomial::new(vec![fq(1), fq(2)]);
+
+        assert!(!poly.probabilistic_eq(&fewer_vars, &mut rng));
+    }
+
+    #[test]
+    fn test_boolean_points() {
+        let mut rng = rand::thread_rng();
+        let evals: Vec<Fq> = (0..1 << 3).map(|_| Fq::rand(&mut rng)).collect();
+        let poly = MultilinearPolynomial::new(evals);
+
+        let points: Vec<Vec<Fq>> = MultilinearPolynomial::boolean_points(3).collect();
+
+        assert_eq!(points[1], vec![fq(0), fq(0), fq(1)]);
+        assert_eq!(points[6], vec![fq(1), fq(1), fq(0)]);
+
+        let reproduced: Vec<Fq> = points.iter().map(|point| poly.evaluate(point)).collect();
+
+        assert_eq!(reproduced, poly.evals_slice());
+    }
+
+    #[test]
+    fn test_from_fn_eq_polynomial() {
+        let mut rng = rand::thread_rng();
+        let n_vars = 3;
+
+        // eq(x, y) = 1 iff x == y on the hypercube
+        let eq = MultilinearPolynomial::<Fq>::from_fn(2 * n_vars, |bits| {
+            if bits[..n_vars] == bits[n_vars..] {
+                fq(1)
+            } else {
+                fq(0)
+            }
+        });
+
+        let x: Vec<Fq> = (0..n_vars).map(|_| Fq::rand(&mut rng)).collect();
+        let y: Vec<Fq> = (0..n_vars).map(|_| Fq::rand(&mut rng)).collect();
+
+        // multilinear extension of eq: prod_i (x_i * y_i + (1 - x_i) * (1 - y_i))
+        let expected: Fq = x
+            .iter()
+            .zip(&y)
+            .map(|(&x_i, &y_i)| x_i * y_i + (fq(1) - x_i) * (fq(1) - y_i))
+            .product();
+        let point: Vec<Fq> = x.into_iter().chain(y).collect();
+
+        assert_eq!(eq.evaluate(&point), expected);
+    }
+
+    #[test]
+    fn test_new_padded() {
+        let poly = MultilinearPolynomial::new_padded(vec![fq(1), fq(2), fq(3)]);
+
+        assert_eq!(poly.n_vars(), 2);
+        assert_eq!(poly.evals_slice(), &[fq(1), fq(2), fq(3), fq(0)]);
+
+        let already_padded = MultilinearPolynomial::new_padded(vec![fq(1), fq(2)]);
+
+        assert_eq!(
+            already_padded,
+            MultilinearPolynomial::new(vec![fq(1), fq(2)])
+        );
+    }
+
+    #[test]
+    fn test_agrees_on_hypercube() {
+        let poly = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(4)]);
+        let padded = MultilinearPolynomial::new_padded(vec![fq(1), fq(2), fq(3), fq(4), fq(0)]);
+
+        assert_eq!(padded.n_vars(), 3);
+        assert!(poly.agrees_on_hypercube(&padded));
+        assert!(padded.agrees_on_hypercube(&poly));
+        assert!(poly.agrees_on_hypercube(&poly));
+
+        let nonzero_tail =
+            MultilinearPolynomial::new_padded(vec![fq(1), fq(2), fq(3), fq(4), fq(5)]);
+
+        assert!(!poly.agrees_on_hypercube(&nonzero_tail));
+
+        let different = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(5)]);
+
+        assert!(!poly.agrees_on_hypercube(&different));
+    }
+
+    #[test]
+    fn test_permute_variables() {
+        let poly = MultilinearPolynomial::new((1..=8).map(fq).collect());
+
+        assert_eq!(poly.permute_variables(&[0, 1, 2]), poly);
+
+        let swapped = MultilinearPolynomial::new(vec![fq(1), fq(3), fq(2), fq(4)]);
+
+        assert_eq!(
+            MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(4)]).permute_variables(&[1, 0]),
+            swapped
+        );
+
+        let permuted = poly.permute_variables(&[2, 0, 1]);
+        let (x, y, z) = (fq(3), fq(5), fq(7));
+
+        assert_eq!(permuted.evaluate(&[x, y, z]), poly.evaluate(&[y, z, x]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Variable permutation must be a permutation of 0..3")]
+    fn test_permute_variables_rejects_repeats() {
+        MultilinearPolynomial::new((1..=8).map(fq).collect()).permute_variables(&[0, 1, 1]);
+    }
+
+    #[test]
+    fn test_write_bytes_matches_to_bytes() {
+        let poly = MultilinearPolynomial::new((1..=8).map(fq).collect());
+        let mut streamed = std::io::Cursor::new(Vec::new());
+
+        poly.write_bytes(&mut streamed).unwrap();
+
+        assert_eq!(streamed.into_inner(), poly.to_bytes());
+        assert_eq!(poly.to_bytes().len(), 8 * 48);
+    }
+
+    #[test]
+    fn test_new_zero() {
+        let mut poly = MultilinearPolynomial::<Fq>::new_zero(3);
+
+        assert_eq!(poly.n_vars(), 3);
+        assert_eq!(poly.len(), 8);
+        assert!(poly.evals_slice().iter().all(|&eval| eval == fq(0)));
+
+        poly.evals_mut()[5] = fq(7);
+
+        assert_eq!(poly.evaluate(&[fq(1), fq(0), fq(1)]), fq(7));
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let poly = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(4)]);
+        let mut bytes = vec![];
+        poly.serialize_compressed(&mut bytes).unwrap();
+
+        assert_eq!(
+            MultilinearPolynomial::<Fq>::deserialize_compressed(&bytes[..]).unwrap(),
+            poly
+        );
+
+        let mut bad_bytes = vec![];
+        vec![fq(1), fq(2), fq(3)]
+            .serialize_compressed(&mut bad_bytes)
+            .unwrap();
+
+        assert!(MultilinearPolynomial::<Fq>::deserialize_compressed(&bad_bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_into_evals() {
+        let poly = MultilinearPolynomial::new(vec![fq(1), fq(2), fq(3), fq(4)]);
+        let evals = poly.evals_slice().to_vec();
+
+        assert_eq!(poly.into_evals(), evals);
+    }
+
+    #[test]
+    fn test_kronecker() {
+        let mut rng = rand::thread_rng();
+        let a = MultilinearPolynomial::new((0..1 << 2).map(|_| Fq::rand(&mut rng)).collect());
+        let b = MultilinearPolynomial::new((0..1 << 3).map(|_| Fq::rand(&mut rng)).collect());
+        let product = a.kronecker(&b);
+
+        assert_eq!(product.n_vars(), 5);
+        assert_eq!(
+            product.evals_slice()[0b10_011],
+            a.evals[0b10] * b.evals[0b011]
+        );
+
+        let point_a: Vec<Fq> = (0..2).map(|_| Fq::rand(&mut rng)).collect();
+        let point_b: Vec<Fq> = (0..3).map(|_| Fq::rand(&mut rng)).collect();
+        let point: Vec<Fq> = point_a.iter().chain(&point_b).copied().collect();
+
+        assert_eq!(
+            product.evaluate(&point),
+            a.evaluate(&point_a) * b.evaluate(&point_b)
+        );
+    }
 }
@@ -0,0 +1,100 @@
+use super::MultilinearPolynomial;
+use ark_ff::PrimeField;
+
+// evaluates one polynomial at many points, keeping the partial evaluations along the last
+// point's prefix. a point sharing its first k coordinates with the previous one starts from
+// the cached polynomial with those k variables already fixed instead of from scratch.
+#[derive(Debug, Clone)]
+pub struct MultilinearPolynomialEvaluator<F: PrimeField> {
+    polynomial: MultilinearPolynomial<F>,
+    // prefix[i] is the value the (i + 1)-th intermediate below was fixed at
+    prefix: Vec<F>,
+    // intermediates[i] has the first i + 1 variables of `polynomial` fixed to prefix[..=i]
+    intermediates: Vec<MultilinearPolynomial<F>>,
+}
+
+impl<F: PrimeField> From<MultilinearPolynomial<F>> for MultilinearPolynomialEvaluator<F> {
+    fn from(polynomial: MultilinearPolynomial<F>) -> Self {
+        Self {
+            polynomial,
+            prefix: vec![],
+            intermediates: vec![],
+        }
+    }
+}
+
+impl<F: PrimeField> MultilinearPolynomialEvaluator<F> {
+    pub fn polynomial(&self) -> &MultilinearPolynomial<F> {
+        &self.polynomial
+    }
+
+    pub fn evaluate(&mut self, points: &[F]) -> F {
+        assert_eq!(
+            points.len(),
+            self.polynomial.n_vars(),
+            "Number of points must match number of variables"
+        );
+
+        if points.is_empty() {
+            return self.polynomial.evals_slice()[0];
+        }
+
+        let shared_prefix_len = self
+            .prefix
+            .iter()
+            .zip(points)
+            .take_while(|(cached, point)| cached == point)
+            .count();
+
+        self.prefix.truncate(shared_prefix_len);
+        self.intermediates.truncate(shared_prefix_len);
+
+        for &point in &points[shared_prefix_len..] {
+            let current = self.intermediates.last().unwrap_or(&self.polynomial);
+            let next = current.partial_evaluate(point, 0);
+
+            self.prefix.push(point);
+            self.intermediates.push(next);
+        }
+
+        self.intermediates[points.len() - 1].evals_slice()[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
+
+    #[test]
+    fn test_evaluate_matches_polynomial() {
+        let mut rng = rand::thread_rng();
+        let n_vars = 6;
+        let evals: Vec<Fq> = (0..1 << n_vars).map(|_| Fq::rand(&mut rng)).collect();
+        let poly = MultilinearPolynomial::new(evals);
+        let mut evaluator = MultilinearPolynomialEvaluator::from(poly.clone());
+
+        let mut point: Vec<Fq> = (0..n_vars).map(|_| Fq::rand(&mut rng)).collect();
+
+        for changed_var in (0..n_vars).rev() {
+            point[changed_var] = Fq::rand(&mut rng);
+
+            assert_eq!(evaluator.evaluate(&point), poly.evaluate(&point));
+        }
+
+        // unrelated point followed by a repeat of it
+        let other: Vec<Fq> = (0..n_vars).map(|_| Fq::rand(&mut rng)).collect();
+
+        assert_eq!(evaluator.evaluate(&other), poly.evaluate(&other));
+        assert_eq!(evaluator.evaluate(&other), poly.evaluate(&other));
+    }
+
+    #[test]
+    fn test_evaluate_constant_polynomial() {
+        let poly = MultilinearPolynomial::new(vec![Fq::from(7u64)]);
+        let mut evaluator = MultilinearPolynomialEvaluator::from(poly);
+
+        assert_eq!(evaluator.evaluate(&[]), Fq::from(7u64));
+    }
+}
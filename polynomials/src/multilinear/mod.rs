@@ -1,5 +1,10 @@
 pub mod dense;
 pub mod evaluation;
+pub mod evaluator;
 pub mod sparse;
+pub mod sparse_evaluation;
 
-pub use evaluation::MultilinearPolynomial;
+pub use crate::util::DecodeError;
+pub use evaluation::{MultilinearPolynomial, verify_merkle_proof};
+pub use evaluator::MultilinearPolynomialEvaluator;
+pub use sparse_evaluation::SparseEvaluationPolynomial;
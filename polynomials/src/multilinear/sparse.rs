@@ -1,9 +1,14 @@
+use crate::display::{multilinear_monomial, write_terms};
 use ark_ff::PrimeField;
 use std::{
     cmp::Ordering,
+    collections::BTreeMap,
+    fmt,
     ops::{Add, Mul},
 };
 
+// `==` is structural: it compares the term lists as stored, so the same polynomial with terms in
+// another order or with unmerged duplicates compares unequal. use `semantically_eq` for that.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SparseMultilinearPolynomial<F: PrimeField> {
     terms: Vec<(F, usize)>,
@@ -15,6 +20,14 @@ impl<F: PrimeField> SparseMultilinearPolynomial<F> {
         Self { terms, n_vars }
     }
 
+    pub fn zero(n_vars: usize) -> Self {
+        Self::new(vec![], n_vars)
+    }
+
+    pub fn one(n_vars: usize) -> Self {
+        Self::new(vec![(F::ONE, 0)], n_vars)
+    }
+
     pub fn n_vars(&self) -> usize {
         self.n_vars
     }
@@ -36,6 +49,25 @@ impl<F: PrimeField> SparseMultilinearPolynomial<F> {
         }
     }
 
+    // merges duplicate monomials, drops zero coefficients and sorts by monomial index
+    fn normalized_terms(&self) -> Vec<(F, usize)> {
+        let mut merged = BTreeMap::new();
+
+        for &(coeff, monomial_index) in &self.terms {
+            *merged.entry(monomial_index).or_insert(F::ZERO) += coeff;
+        }
+
+        merged
+            .into_iter()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(monomial_index, coeff)| (coeff, monomial_index))
+            .collect()
+    }
+
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.n_vars == other.n_vars && self.normalized_terms() == other.normalized_terms()
+    }
+
     pub fn evaluate(&self, point: &[F]) -> F {
         assert_eq!(
             point.len(),
@@ -97,6 +129,38 @@ impl<F: PrimeField> SparseMultilinearPolynomial<F> {
         SparseMultilinearPolynomial::new(combined_terms, self.n_vars)
     }
 
+    // like `partial_evaluate`, but the remaining variables are renumbered 0, 1, .. in their
+    // original order, so the result has n_vars - partial_terms.len() variables the way the
+    // dense partial evaluation does. terms that land on the same monomial are merged.
+    pub fn partial_evaluate_and_collapse(&self, partial_terms: &[(F, usize)]) -> Self {
+        let remaining_vars: Vec<usize> = (0..self.n_vars)
+            .filter(|&var| !partial_terms.iter().any(|&(_, pos)| pos == var))
+            .collect();
+
+        let terms = self
+            .terms
+            .iter()
+            .map(|&(coeff, monomial_index)| {
+                let new_coeff = partial_terms
+                    .iter()
+                    .filter(|&&(_, pos)| monomial_index & (1 << pos) != 0)
+                    .fold(coeff, |acc, &(val, _)| acc * val);
+
+                let collapsed_index = remaining_vars
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &var)| monomial_index & (1 << var) != 0)
+                    .fold(0, |acc, (shift, _)| acc | (1 << shift));
+
+                (new_coeff, collapsed_index)
+            })
+            .collect();
+
+        let collapsed = SparseMultilinearPolynomial::new(terms, remaining_vars.len());
+
+        SparseMultilinearPolynomial::new(collapsed.normalized_terms(), remaining_vars.len())
+    }
+
     fn basis(point: &[u8], val: F) -> Self {
         let n_vars = point.len();
         let mut poly = SparseMultilinearPolynomial::new(vec![(val, 0)], n_vars);
@@ -209,15 +273,69 @@ impl<F: PrimeField> Mul for &SparseMultilinearPolynomial<F> {
     }
 }
 
+impl<F: PrimeField> Mul<F> for SparseMultilinearPolynomial<F> {
+    type Output = SparseMultilinearPolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &SparseMultilinearPolynomial<F> {
+    type Output = SparseMultilinearPolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl<F: PrimeField> fmt::Display for SparseMultilinearPolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut terms = self.terms.clone();
+        terms.sort_by_key(|&(_, monomial_index)| std::cmp::Reverse(monomial_index));
+
+        write_terms(
+            f,
+            terms
+                .into_iter()
+                .map(|(coeff, i)| (coeff, multilinear_monomial(i, self.n_vars))),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::{assert_mul_by_scalar, assert_zero_and_one_are_identities};
     use ark_bls12_381::Fq;
 
     fn fq(val: u64) -> Fq {
         Fq::from(val)
     }
 
+    #[test]
+    fn test_zero_and_one_are_identities() {
+        // f(x, y) = 1 + 2x + 3xy
+        let poly =
+            SparseMultilinearPolynomial::new(vec![(fq(1), 0b00), (fq(2), 0b01), (fq(3), 0b11)], 2);
+
+        assert_zero_and_one_are_identities(
+            &poly,
+            &SparseMultilinearPolynomial::zero(2),
+            &SparseMultilinearPolynomial::one(2),
+            |a, b| a + b,
+            |a, b| a * b,
+        );
+    }
+
+    #[test]
+    fn test_mul_by_scalar() {
+        let poly =
+            SparseMultilinearPolynomial::new(vec![(fq(3), 0b11), (fq(2), 0b01), (fq(1), 0b00)], 2);
+
+        assert_mul_by_scalar(&poly, fq(7), &poly.scalar_mul(fq(7)));
+    }
+
     #[test]
     fn test_scalar_mul() {
         let n_vars = 2;
@@ -320,6 +438,30 @@ mod tests {
         assert_eq!(partially_evaluated, expected_poly);
     }
 
+    #[test]
+    fn test_partial_evaluate_and_collapse() {
+        // f(x, y, z) = 2xyz + 5xz + 3y + 1
+        let poly = SparseMultilinearPolynomial::new(
+            vec![
+                (fq(2), 0b111), // xyz
+                (fq(5), 0b101), // xz
+                (fq(3), 0b010), // y
+                (fq(1), 0b000), // constant
+            ],
+            3,
+        );
+
+        // fix y = 4, leaving g(x, z) = 13xz + 13 with x -> bit 0 and z -> bit 1
+        let collapsed = poly.partial_evaluate_and_collapse(&[(fq(4), 1)]);
+
+        assert_eq!(collapsed.n_vars(), 2);
+        assert_eq!(collapsed.terms_slice(), &[(fq(13), 0b00), (fq(13), 0b11)]);
+
+        let (x, z) = (fq(6), fq(7));
+
+        assert_eq!(collapsed.evaluate(&[x, z]), poly.evaluate(&[x, fq(4), z]));
+    }
+
     #[test]
     fn test_partial_evaluate_all_vars() {
         let n_vars = 2;
@@ -365,4 +507,41 @@ mod tests {
 
         assert_eq!(poly, expected_poly);
     }
+
+    #[test]
+    fn test_display() {
+        // f(x0, x1) = 3x0x1 + 2x0 + 4
+        let poly =
+            SparseMultilinearPolynomial::new(vec![(fq(2), 0b01), (fq(4), 0b00), (fq(3), 0b11)], 2);
+
+        assert_eq!(poly.to_string(), "3x0x1 + 2x0 + 4");
+    }
+
+    #[test]
+    fn test_semantically_eq() {
+        // f(x, y) = 3xy + 2x + 4
+        let poly =
+            SparseMultilinearPolynomial::new(vec![(fq(4), 0b00), (fq(2), 0b01), (fq(3), 0b11)], 2);
+        let reordered =
+            SparseMultilinearPolynomial::new(vec![(fq(3), 0b11), (fq(4), 0b00), (fq(2), 0b01)], 2);
+        let unmerged = SparseMultilinearPolynomial::new(
+            vec![
+                (fq(1), 0b01),
+                (fq(4), 0b00),
+                (fq(3), 0b11),
+                (fq(1), 0b01),
+                (fq(0), 0b10),
+            ],
+            2,
+        );
+
+        assert_ne!(poly, reordered);
+        assert_ne!(poly, unmerged);
+        assert!(poly.semantically_eq(&reordered));
+        assert!(poly.semantically_eq(&unmerged));
+        assert!(!poly.semantically_eq(&SparseMultilinearPolynomial::new(
+            vec![(fq(4), 0b00), (fq(2), 0b01)],
+            2
+        )));
+    }
 }
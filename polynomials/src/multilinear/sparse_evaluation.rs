@@ -0,0 +1,105 @@
+use super::MultilinearPolynomial;
+use ark_ff::PrimeField;
+
+// evaluation form like `MultilinearPolynomial`, but only the non-zero hypercube evaluations
+// are stored as (value, index) pairs. Indices follow the same lex order, so the first
+// variable is the most significant bit of the index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseEvaluationPolynomial<F: PrimeField> {
+    terms: Vec<(F, usize)>,
+    n_vars: usize,
+}
+
+impl<F: PrimeField> SparseEvaluationPolynomial<F> {
+    pub fn new(terms: Vec<(F, usize)>, n_vars: usize) -> Self {
+        assert!(
+            terms.iter().all(|&(_, index)| index < 1 << n_vars),
+            "Evaluation index out of range for the number of variables"
+        );
+
+        Self { terms, n_vars }
+    }
+
+    pub fn n_vars(&self) -> usize {
+        self.n_vars
+    }
+
+    pub fn terms_slice(&self) -> &[(F, usize)] {
+        &self.terms
+    }
+
+    pub fn evaluate(&self, points: &[F]) -> F {
+        assert_eq!(
+            points.len(),
+            self.n_vars,
+            "Number of points must match number of variables"
+        );
+
+        // each stored evaluation is weighted by eq(index, points)
+        self.terms
+            .iter()
+            .map(|&(value, index)| {
+                points.iter().enumerate().fold(value, |acc, (i, &point)| {
+                    if index & (1 << (self.n_vars - i - 1)) != 0 {
+                        acc * point
+                    } else {
+                        acc * (F::ONE - point)
+                    }
+                })
+            })
+            .sum()
+    }
+
+    pub fn to_dense(&self) -> MultilinearPolynomial<F> {
+        let mut evals = vec![F::ZERO; 1 << self.n_vars];
+
+        for &(value, index) in &self.terms {
+            evals[index] += value;
+        }
+
+        MultilinearPolynomial::new(evals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
+
+    fn fq(x: u64) -> Fq {
+        Fq::from(x)
+    }
+
+    #[test]
+    fn test_to_dense() {
+        let poly = SparseEvaluationPolynomial::new(vec![(fq(3), 1), (fq(5), 6)], 3);
+        let expected = MultilinearPolynomial::new(vec![
+            fq(0),
+            fq(3),
+            fq(0),
+            fq(0),
+            fq(0),
+            fq(0),
+            fq(5),
+            fq(0),
+        ]);
+
+        assert_eq!(poly.to_dense(), expected);
+    }
+
+    #[test]
+    fn test_evaluate_matches_dense() {
+        let mut rng = rand::thread_rng();
+        let poly = SparseEvaluationPolynomial::new(vec![(fq(3), 1), (fq(5), 6), (fq(7), 7)], 3);
+        let point: Vec<Fq> = (0..3).map(|_| Fq::rand(&mut rng)).collect();
+
+        assert_eq!(poly.evaluate(&point), poly.to_dense().evaluate(&point));
+    }
+
+    #[test]
+    #[should_panic(expected = "Evaluation index out of range for the number of variables")]
+    fn test_new_index_out_of_range() {
+        SparseEvaluationPolynomial::new(vec![(fq(1), 4)], 2);
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt::Debug;
+use std::ops::Mul;
+
+// `Mul<F>` is implemented on both the owned and the borrowed polynomial and has to agree
+// with `scalar_mul`, passed in as `expected`
+pub(crate) fn assert_mul_by_scalar<F, P>(poly: &P, scalar: F, expected: &P)
+where
+    F: Copy,
+    P: Clone + Debug + PartialEq + Mul<F, Output = P>,
+    for<'a> &'a P: Mul<F, Output = P>,
+{
+    assert_eq!(&(poly * scalar), expected);
+    assert_eq!(&(poly.clone() * scalar), expected);
+}
+
+// `zero` and `one` are the identities of `add` and `mul`, from either side
+pub(crate) fn assert_zero_and_one_are_identities<P: Debug + PartialEq>(
+    poly: &P,
+    zero: &P,
+    one: &P,
+    add: impl Fn(&P, &P) -> P,
+    mul: impl Fn(&P, &P) -> P,
+) {
+    assert_eq!(&add(poly, zero), poly);
+    assert_eq!(&add(zero, poly), poly);
+    assert_eq!(&mul(poly, one), poly);
+    assert_eq!(&mul(one, poly), poly);
+}
@@ -1,9 +1,14 @@
-use ark_ff::{BigInteger, PrimeField};
+use super::error::{InterpolationError, check_interpolation_points};
+use crate::display::{univariate_monomial, write_terms};
+use crate::util::{DecodeError, field_byte_size, field_from_bytes, field_to_bytes};
+use ark_ff::{PrimeField, batch_inversion};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use std::fmt;
 use std::iter::{Product, Sum};
 use std::ops::{Add, Mul};
 
 // dense polynomial
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct DenseUnivariatePolynomial<F: PrimeField> {
     // 1 coefficient for each power of x
     coefficients: Vec<F>,
@@ -14,14 +19,37 @@ impl<F: PrimeField> DenseUnivariatePolynomial<F> {
         Self { coefficients }
     }
 
+    pub fn zero() -> Self {
+        Self::new(vec![F::ZERO])
+    }
+
+    pub fn one() -> Self {
+        Self::new(vec![F::ONE])
+    }
+
+    // index of the highest non-zero coefficient, so trailing zero coefficients don't count
+    // and the empty and all-zero polynomials have degree 0 like the sparse variant
     pub fn degree(&self) -> usize {
-        self.coefficients.len() - 1
+        self.coefficients
+            .iter()
+            .rposition(|coeff| !coeff.is_zero())
+            .unwrap_or(0)
     }
 
     pub fn coefficients_slice(&self) -> &[F] {
         &self.coefficients
     }
 
+    pub fn into_coefficients(self) -> Vec<F> {
+        self.coefficients
+    }
+
+    // drops trailing zero coefficients so the representation is unique and the coefficient
+    // count is degree() + 1. the zero polynomial keeps a single zero coefficient.
+    pub fn normalize(&mut self) {
+        self.coefficients.truncate(self.degree() + 1);
+    }
+
     pub fn scalar_mul(&self, scalar: F) -> Self {
         DenseUnivariatePolynomial {
             coefficients: self
@@ -32,6 +60,96 @@ impl<F: PrimeField> DenseUnivariatePolynomial<F> {
         }
     }
 
+    pub fn scalar_div(&self, scalar: F) -> Self {
+        let inverse = scalar
+            .inverse()
+            .expect("Cannot divide a polynomial by the zero scalar");
+
+        self.scalar_mul(inverse)
+    }
+
+    // scaled so the coefficient at `degree()` is one
+    pub fn make_monic(&self) -> Self {
+        let leading_coefficient = self
+            .coefficients
+            .get(self.degree())
+            .copied()
+            .unwrap_or(F::ZERO);
+
+        assert!(
+            !leading_coefficient.is_zero(),
+            "The zero polynomial can't be made monic"
+        );
+
+        self.scalar_div(leading_coefficient)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.iter().all(|coeff| coeff.is_zero())
+    }
+
+    // long division, self = quotient * divisor + remainder with deg(remainder) < deg(divisor).
+    // both results are normalized.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "Cannot divide by the zero polynomial");
+
+        let mut divisor = divisor.clone();
+        divisor.normalize();
+
+        let mut remainder = self.clone();
+        remainder.normalize();
+
+        if remainder.coefficients.len() < divisor.coefficients.len() {
+            if remainder.coefficients.is_empty() {
+                remainder.coefficients.push(F::ZERO);
+            }
+
+            return (Self::new(vec![F::ZERO]), remainder);
+        }
+
+        let divisor_degree = divisor.degree();
+        let leading_inverse = divisor.coefficients[divisor_degree]
+            .inverse()
+            .expect("leading coefficient of a normalized polynomial is non-zero");
+        let mut quotient = vec![F::ZERO; remainder.coefficients.len() - divisor_degree];
+
+        for i in (0..quotient.len()).rev() {
+            let coeff = remainder.coefficients[i + divisor_degree] * leading_inverse;
+            quotient[i] = coeff;
+
+            for (j, divisor_coeff) in divisor.coefficients.iter().enumerate() {
+                remainder.coefficients[i + j] -= coeff * divisor_coeff;
+            }
+        }
+
+        let mut quotient = Self::new(quotient);
+        quotient.normalize();
+        remainder.normalize();
+
+        (quotient, remainder)
+    }
+
+    // monic gcd via the euclidean algorithm. gcd(p, 0) is p made monic and gcd(0, 0) is the
+    // zero polynomial.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+
+        while !b.is_zero() {
+            let (_, remainder) = a.div_rem(&b);
+            a = b;
+            b = remainder;
+        }
+
+        if a.is_zero() {
+            return Self::new(vec![F::ZERO]);
+        }
+
+        let mut gcd = a.make_monic();
+        gcd.normalize();
+
+        gcd
+    }
+
     pub fn basis(x: F, interpolating_set: &[F]) -> Self {
         //  numerator
         let numerators = interpolating_set
@@ -57,29 +175,209 @@ impl<F: PrimeField> DenseUnivariatePolynomial<F> {
     }
 
     pub fn interpolate(xs: &[F], ys: &[F]) -> Self {
-        assert_eq!(xs.len(), ys.len());
+        Self::try_interpolate(xs, ys).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    // duplicate xs leave the lagrange basis ill-defined, so they are rejected up front
+    pub fn try_interpolate(xs: &[F], ys: &[F]) -> Result<Self, InterpolationError> {
+        check_interpolation_points(xs, ys)?;
 
         // dot product between the ys and the lagrange basis
-        xs.iter()
+        let mut poly: Self = xs
+            .iter()
             .zip(ys.iter())
             .map(|(x, y)| Self::basis(*x, xs).scalar_mul(*y))
-            .sum()
+            .sum();
+
+        poly.normalize();
+
+        Ok(poly)
     }
 
-    pub fn interpolate_y(ys: Vec<F>) -> Self {
-        let mut xs = vec![];
-        for i in 0..ys.len() {
-            xs.push(F::from(i as u64));
+    // for callers that know the degree the points should lie on, e.g. a Shamir recovery
+    // with the wrong threshold gives a polynomial of the wrong degree
+    pub fn interpolate_with_degree(
+        xs: &[F],
+        ys: &[F],
+        expected_degree: usize,
+    ) -> Result<Self, InterpolationError> {
+        let poly = Self::try_interpolate(xs, ys)?;
+
+        if poly.degree() != expected_degree {
+            return Err(InterpolationError::DegreeMismatch {
+                expected: expected_degree,
+                actual: poly.degree(),
+            });
         }
-        Self::interpolate(&xs, &ys)
+
+        Ok(poly)
+    }
+
+    // the lagrange basis only depends on the xs, so it is built once and shared by every
+    // y-vector instead of once per `interpolate` call
+    pub fn interpolate_many(xs: &[F], yss: &[Vec<F>]) -> Vec<Self> {
+        for ys in yss {
+            check_interpolation_points(xs, ys).unwrap_or_else(|err| panic!("{}", err));
+        }
+
+        let basis: Vec<Self> = xs.iter().map(|x| Self::basis(*x, xs)).collect();
+
+        yss.iter()
+            .map(|ys| {
+                let mut poly: Self = basis
+                    .iter()
+                    .zip(ys)
+                    .map(|(basis_poly, y)| basis_poly.scalar_mul(*y))
+                    .sum();
+
+                poly.normalize();
+
+                poly
+            })
+            .collect()
+    }
+
+    // barycentric weights w_j = 1 / prod_{k != j} (x_j - x_k), O(n^2) once for all points
+    fn barycentric_weights(xs: &[F]) -> Vec<F> {
+        let mut weights: Vec<F> = xs
+            .iter()
+            .enumerate()
+            .map(|(j, x_j)| {
+                xs.iter()
+                    .enumerate()
+                    .filter(|&(k, _)| k != j)
+                    .map(|(_, x_k)| *x_j - x_k)
+                    .product()
+            })
+            .collect();
+
+        batch_inversion(&mut weights);
+
+        weights
+    }
+
+    // O(n^2) alternative to `interpolate`: every basis numerator is the master polynomial
+    // prod_k (x - x_k) divided by (x - x_j), which synthetic division gives in O(n)
+    pub fn interpolate_barycentric(xs: &[F], ys: &[F]) -> Self {
+        check_interpolation_points(xs, ys).unwrap_or_else(|err| panic!("{}", err));
+
+        let weights = Self::barycentric_weights(xs);
+
+        let mut master = vec![F::ONE];
+
+        for x in xs {
+            master = (&Self::new(master) * &Self::new(vec![x.neg(), F::ONE])).coefficients;
+        }
+
+        let mut coefficients = vec![F::ZERO; xs.len().max(1)];
+
+        for ((x, y), weight) in xs.iter().zip(ys).zip(weights) {
+            let scale = *y * weight;
+            let mut carry = F::ZERO;
+
+            // synthetic division of the master polynomial by (x - x_j), from the top
+            for i in (0..xs.len()).rev() {
+                carry = master[i + 1] + carry * x;
+                coefficients[i] += carry * scale;
+            }
+        }
+
+        Self::new(coefficients)
+    }
+
+    // evaluates the interpolant through (xs, ys) at z without building its coefficients,
+    // using the second barycentric form sum_j (w_j / (z - x_j)) y_j / sum_j w_j / (z - x_j)
+    pub fn evaluate_interpolant_at(xs: &[F], ys: &[F], z: F) -> F {
+        check_interpolation_points(xs, ys).unwrap_or_else(|err| panic!("{}", err));
+
+        if let Some(j) = xs.iter().position(|x| *x == z) {
+            return ys[j];
+        }
+
+        let weights = Self::barycentric_weights(xs);
+        let mut inverse_differences: Vec<F> = xs.iter().map(|x| z - x).collect();
+
+        batch_inversion(&mut inverse_differences);
+
+        let (numerator, denominator) = inverse_differences.iter().zip(&weights).zip(ys).fold(
+            (F::ZERO, F::ZERO),
+            |(num, den), ((inv, w), y)| {
+                let term = *w * inv;
+
+                (num + term * y, den + term)
+            },
+        );
+
+        numerator / denominator
+    }
+
+    /// Interpolates through the points (0, ys[0]), (1, ys[1]), ... Like `interpolate`, it
+    /// takes its points as slices.
+    ///
+    /// ```
+    /// use ark_bls12_381::Fq;
+    /// use polynomials::univariate::DenseUnivariatePolynomial;
+    ///
+    /// let xs = vec![Fq::from(0u64), Fq::from(1u64), Fq::from(2u64)];
+    /// let ys = vec![Fq::from(1u64), Fq::from(6u64), Fq::from(17u64)];
+    ///
+    /// assert_eq!(
+    ///     DenseUnivariatePolynomial::interpolate(&xs, &ys),
+    ///     DenseUnivariatePolynomial::interpolate_y(&ys)
+    /// );
+    /// ```
+    pub fn interpolate_y(ys: &[F]) -> Self {
+        let xs: Vec<F> = (0..ys.len()).map(|i| F::from(i as u64)).collect();
+
+        Self::interpolate(&xs, ys)
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes_with_endianness(false)
+    }
+
+    pub fn to_bytes_with_endianness(&self, little_endian: bool) -> Vec<u8> {
         self.coefficients
             .iter()
-            .flat_map(|coeff| coeff.into_bigint().to_bytes_be())
+            .flat_map(|coeff| field_to_bytes(coeff, little_endian))
             .collect()
     }
+
+    // inverse of `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::from_bytes_with_endianness(bytes, false)
+    }
+
+    // inverse of `to_bytes_with_endianness`. any number of coefficients decodes, but each one
+    // must be a canonical field element of the full `F::BigInt` width. trailing zero
+    // coefficients are dropped like `interpolate` does
+    pub fn from_bytes_with_endianness(
+        bytes: &[u8],
+        little_endian: bool,
+    ) -> Result<Self, DecodeError> {
+        let element_size = field_byte_size::<F>();
+
+        if !bytes.len().is_multiple_of(element_size) {
+            return Err(DecodeError::LengthNotMultipleOfElementSize {
+                len: bytes.len(),
+                element_size,
+            });
+        }
+
+        let coefficients = bytes
+            .chunks(element_size)
+            .enumerate()
+            .map(|(index, chunk)| {
+                field_from_bytes(chunk, little_endian)
+                    .ok_or(DecodeError::NonCanonicalCoefficient { index })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut poly = Self::new(coefficients);
+        poly.normalize();
+
+        Ok(poly)
+    }
 }
 
 impl<F: PrimeField> Mul for &DenseUnivariatePolynomial<F> {
@@ -87,8 +385,11 @@ impl<F: PrimeField> Mul for &DenseUnivariatePolynomial<F> {
 
     fn mul(self, rhs: Self) -> Self::Output {
         // mul for dense
-        let new_degree = self.degree() + rhs.degree();
-        let mut result = vec![F::ZERO; new_degree + 1];
+        if self.coefficients.is_empty() || rhs.coefficients.is_empty() {
+            return DenseUnivariatePolynomial::new(vec![]);
+        }
+
+        let mut result = vec![F::ZERO; self.coefficients.len() + rhs.coefficients.len() - 1];
         for i in 0..self.coefficients.len() {
             for j in 0..rhs.coefficients.len() {
                 result[i + j] += self.coefficients[i] * rhs.coefficients[j]
@@ -101,9 +402,25 @@ impl<F: PrimeField> Mul for &DenseUnivariatePolynomial<F> {
     }
 }
 
+impl<F: PrimeField> Mul<F> for DenseUnivariatePolynomial<F> {
+    type Output = DenseUnivariatePolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &DenseUnivariatePolynomial<F> {
+    type Output = DenseUnivariatePolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
 impl<F: PrimeField> Product for DenseUnivariatePolynomial<F> {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut result = DenseUnivariatePolynomial::new(vec![F::ONE]);
+        let mut result = DenseUnivariatePolynomial::one();
 
         for poly in iter {
             result = &result * &poly
@@ -117,7 +434,7 @@ impl<F: PrimeField> Add for &DenseUnivariatePolynomial<F> {
     type Output = DenseUnivariatePolynomial<F>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let (mut bigger_poly, smaller_poly) = if self.degree() < rhs.degree() {
+        let (mut bigger_poly, smaller_poly) = if self.coefficients.len() < rhs.coefficients.len() {
             (rhs.clone(), self)
         } else {
             (self.clone(), rhs)
@@ -136,7 +453,7 @@ impl<F: PrimeField> Add for &DenseUnivariatePolynomial<F> {
 
 impl<F: PrimeField> Sum for DenseUnivariatePolynomial<F> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut result = DenseUnivariatePolynomial::new(vec![F::ZERO]);
+        let mut result = DenseUnivariatePolynomial::zero();
 
         for poly in iter {
             result = &result + &poly;
@@ -146,9 +463,23 @@ impl<F: PrimeField> Sum for DenseUnivariatePolynomial<F> {
     }
 }
 
+impl<F: PrimeField> fmt::Display for DenseUnivariatePolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_terms(
+            f,
+            self.coefficients
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(exp, coeff)| (*coeff, univariate_monomial(exp))),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::{assert_mul_by_scalar, assert_zero_and_one_are_identities};
     use ark_bls12_381::Fq;
 
     fn fq(x: u64) -> Fq {
@@ -161,6 +492,109 @@ mod tests {
         DenseUnivariatePolynomial::new(coeffs)
     }
 
+    #[test]
+    fn test_interpolate_with_degree() {
+        // 5 points on f(x) = 1 + 2x + 3x^2
+        let poly = test_poly();
+        let xs: Vec<Fq> = (0..5).map(fq).collect();
+        let ys: Vec<Fq> = xs.iter().map(|&x| poly.evaluate(x)).collect();
+
+        assert_eq!(
+            DenseUnivariatePolynomial::interpolate_with_degree(&xs, &ys, 4),
+            Err(InterpolationError::DegreeMismatch {
+                expected: 4,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            DenseUnivariatePolynomial::interpolate_with_degree(&xs, &ys, 2),
+            Ok(poly)
+        );
+    }
+
+    #[test]
+    fn test_zero_and_one_are_identities() {
+        assert_zero_and_one_are_identities(
+            &test_poly(),
+            &DenseUnivariatePolynomial::zero(),
+            &DenseUnivariatePolynomial::one(),
+            |a, b| a + b,
+            |a, b| a * b,
+        );
+    }
+
+    #[test]
+    fn test_mul_by_scalar() {
+        let poly = DenseUnivariatePolynomial::new(vec![fq(1), fq(2), fq(3)]);
+
+        assert_mul_by_scalar(&poly, fq(7), &poly.scalar_mul(fq(7)));
+    }
+
+    #[test]
+    fn test_to_bytes_with_endianness() {
+        let poly = DenseUnivariatePolynomial::new(vec![fq(1), fq(2)]);
+        let big_endian = poly.to_bytes_with_endianness(false);
+        let little_endian = poly.to_bytes_with_endianness(true);
+
+        assert_eq!(big_endian, poly.to_bytes());
+        assert_ne!(big_endian, little_endian);
+
+        // each coefficient is reversed in place, the coefficient order is kept
+        let element_size = big_endian.len() / 2;
+        for (be, le) in big_endian
+            .chunks(element_size)
+            .zip(little_endian.chunks(element_size))
+        {
+            assert!(be.iter().eq(le.iter().rev()));
+        }
+
+        assert_eq!(
+            DenseUnivariatePolynomial::from_bytes_with_endianness(&big_endian, false),
+            Ok(poly.clone())
+        );
+        assert_eq!(
+            DenseUnivariatePolynomial::from_bytes_with_endianness(&little_endian, true),
+            Ok(poly.clone())
+        );
+        assert_ne!(
+            DenseUnivariatePolynomial::from_bytes_with_endianness(&little_endian, false),
+            Ok(poly.clone())
+        );
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let poly = DenseUnivariatePolynomial::new(vec![fq(1), fq(2)]);
+        let padded = DenseUnivariatePolynomial::new(vec![fq(1), fq(2), fq(0), fq(0)]);
+
+        assert_eq!(
+            DenseUnivariatePolynomial::from_bytes(&poly.to_bytes()),
+            Ok(poly.clone())
+        );
+        assert_eq!(
+            DenseUnivariatePolynomial::from_bytes(&padded.to_bytes()),
+            Ok(poly)
+        );
+
+        let bytes = padded.to_bytes();
+
+        assert_eq!(
+            DenseUnivariatePolynomial::<Fq>::from_bytes(&bytes[1..]),
+            Err(DecodeError::LengthNotMultipleOfElementSize {
+                len: bytes.len() - 1,
+                element_size: 48
+            })
+        );
+
+        let mut out_of_range = bytes;
+        out_of_range[48..96].fill(0xff);
+
+        assert_eq!(
+            DenseUnivariatePolynomial::<Fq>::from_bytes(&out_of_range),
+            Err(DecodeError::NonCanonicalCoefficient { index: 1 })
+        );
+    }
+
     #[test]
     fn test_degree() {
         let poly = test_poly();
@@ -183,6 +617,93 @@ mod tests {
         assert_eq!(poly.scalar_mul(fq(2)), expected_result);
     }
 
+    #[test]
+    fn test_make_monic() {
+        let poly = DenseUnivariatePolynomial::new(vec![fq(4), fq(2)]);
+
+        assert_eq!(
+            poly.make_monic(),
+            DenseUnivariatePolynomial::new(vec![fq(2), fq(1)])
+        );
+        assert_eq!(poly.scalar_div(fq(2)), poly.make_monic());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide a polynomial by the zero scalar")]
+    fn test_scalar_div_by_zero() {
+        test_poly().scalar_div(fq(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "The zero polynomial can't be made monic")]
+    fn test_make_monic_zero_polynomial() {
+        DenseUnivariatePolynomial::new(vec![fq(0), fq(0)]).make_monic();
+    }
+
+    #[test]
+    fn test_div_rem() {
+        // 2x^3 + 3x^2 + 5 = (x^2 + 1)(2x + 3) + (-2x + 2)
+        let dividend = DenseUnivariatePolynomial::new(vec![fq(5), fq(0), fq(3), fq(2)]);
+        let divisor = DenseUnivariatePolynomial::new(vec![fq(1), fq(0), fq(1), fq(0)]);
+
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+
+        assert_eq!(quotient, DenseUnivariatePolynomial::new(vec![fq(3), fq(2)]));
+        assert_eq!(
+            remainder,
+            DenseUnivariatePolynomial::new(vec![fq(2), -fq(2)])
+        );
+
+        let mut recombined = &(&quotient * &divisor) + &remainder;
+        recombined.normalize();
+
+        assert_eq!(recombined, dividend);
+
+        let (quotient, remainder) = divisor.div_rem(&dividend);
+
+        assert_eq!(quotient, DenseUnivariatePolynomial::new(vec![fq(0)]));
+        assert_eq!(
+            remainder,
+            DenseUnivariatePolynomial::new(vec![fq(1), fq(0), fq(1)])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide by the zero polynomial")]
+    fn test_div_rem_by_zero() {
+        test_poly().div_rem(&DenseUnivariatePolynomial::new(vec![fq(0)]));
+    }
+
+    #[test]
+    fn test_gcd() {
+        // x^2 - 1 and 2x - 2 share the factor x - 1
+        let x_squared_minus_one = DenseUnivariatePolynomial::new(vec![-fq(1), fq(0), fq(1)]);
+        let two_x_minus_two = DenseUnivariatePolynomial::new(vec![-fq(2), fq(2)]);
+        let x_minus_one = DenseUnivariatePolynomial::new(vec![-fq(1), fq(1)]);
+
+        assert_eq!(x_squared_minus_one.gcd(&two_x_minus_two), x_minus_one);
+        assert_eq!(two_x_minus_two.gcd(&x_squared_minus_one), x_minus_one);
+
+        // x + 2 and x + 3 are coprime
+        let coprime = DenseUnivariatePolynomial::new(vec![fq(2), fq(1)])
+            .gcd(&DenseUnivariatePolynomial::new(vec![fq(3), fq(1)]));
+
+        assert_eq!(coprime, DenseUnivariatePolynomial::new(vec![fq(1)]));
+    }
+
+    #[test]
+    fn test_gcd_with_zero() {
+        let zero = DenseUnivariatePolynomial::new(vec![fq(0), fq(0)]);
+        let poly = DenseUnivariatePolynomial::new(vec![fq(4), fq(2), fq(0)]);
+
+        assert_eq!(
+            poly.gcd(&zero),
+            DenseUnivariatePolynomial::new(vec![fq(2), fq(1)])
+        );
+        assert_eq!(zero.gcd(&poly), poly.gcd(&zero));
+        assert_eq!(zero.gcd(&zero), DenseUnivariatePolynomial::new(vec![fq(0)]));
+    }
+
     #[test]
     fn test_addition() {
         let poly_1 = test_poly();
@@ -240,4 +761,144 @@ mod tests {
 
         assert_eq!(interpolated_poly, expected_result);
     }
+
+    #[test]
+    fn test_interpolation_of_collinear_points_is_normalized() {
+        // f(x) = 2x + 1 through three points, so the x^2 coefficient comes out zero
+        let poly =
+            DenseUnivariatePolynomial::interpolate(&[fq(1), fq(2), fq(3)], &[fq(3), fq(5), fq(7)]);
+
+        assert_eq!(poly.degree(), 1);
+        assert_eq!(poly.coefficients_slice(), &[fq(1), fq(2)]);
+    }
+
+    #[test]
+    fn test_interpolate_many() {
+        let xs = [fq(0), fq(1), fq(2)];
+        let yss = vec![
+            vec![fq(1), fq(3), fq(7)],
+            vec![fq(5), fq(5), fq(5)],
+            vec![-fq(2), fq(0), fq(9)],
+        ];
+
+        let polys = DenseUnivariatePolynomial::interpolate_many(&xs, &yss);
+
+        assert_eq!(polys.len(), yss.len());
+
+        for (poly, ys) in polys.iter().zip(&yss) {
+            assert_eq!(poly, &DenseUnivariatePolynomial::interpolate(&xs, ys));
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(test_poly().to_string(), "3x^2 + 2x + 1");
+        assert_eq!(
+            DenseUnivariatePolynomial::new(vec![-fq(5), fq(0), fq(1), -fq(1)]).to_string(),
+            "-x^3 + x^2 - 5"
+        );
+        assert_eq!(
+            DenseUnivariatePolynomial::new(vec![fq(0), fq(0)]).to_string(),
+            "0"
+        );
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let poly = test_poly();
+        let mut bytes = vec![];
+        poly.serialize_compressed(&mut bytes).unwrap();
+
+        assert_eq!(
+            DenseUnivariatePolynomial::deserialize_compressed(&bytes[..]).unwrap(),
+            poly
+        );
+    }
+
+    #[test]
+    fn test_try_interpolate() {
+        assert_eq!(
+            DenseUnivariatePolynomial::try_interpolate(&[fq(2), fq(2)], &[fq(4), fq(8)]),
+            Err(InterpolationError::DuplicateX)
+        );
+        assert_eq!(
+            DenseUnivariatePolynomial::try_interpolate(&[fq(2), fq(4)], &[fq(4)]),
+            Err(InterpolationError::LengthMismatch {
+                num_xs: 2,
+                num_ys: 1
+            })
+        );
+        assert_eq!(
+            DenseUnivariatePolynomial::try_interpolate(&[fq(2), fq(4)], &[fq(4), fq(8)]).unwrap(),
+            DenseUnivariatePolynomial::interpolate(&[fq(2), fq(4)], &[fq(4), fq(8)])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation points must have distinct x-coordinates")]
+    fn test_interpolate_duplicate_x_panics() {
+        DenseUnivariatePolynomial::interpolate(&[fq(2), fq(2)], &[fq(4), fq(8)]);
+    }
+
+    #[test]
+    fn test_interpolate_barycentric() {
+        use ark_ff::UniformRand;
+
+        let mut rng = rand::thread_rng();
+        let xs: Vec<Fq> = (0..10).map(|_| Fq::rand(&mut rng)).collect();
+        let ys: Vec<Fq> = (0..10).map(|_| Fq::rand(&mut rng)).collect();
+        let poly = DenseUnivariatePolynomial::interpolate(&xs, &ys);
+
+        assert_eq!(
+            DenseUnivariatePolynomial::interpolate_barycentric(&xs, &ys),
+            poly
+        );
+
+        let z = Fq::rand(&mut rng);
+
+        assert_eq!(
+            DenseUnivariatePolynomial::evaluate_interpolant_at(&xs, &ys, z),
+            poly.evaluate(z)
+        );
+        assert_eq!(
+            DenseUnivariatePolynomial::evaluate_interpolant_at(&xs, &ys, xs[3]),
+            ys[3]
+        );
+    }
+
+    #[test]
+    fn test_into_coefficients() {
+        let poly = test_poly();
+        let coefficients = poly.coefficients_slice().to_vec();
+
+        assert_eq!(poly.into_coefficients(), coefficients);
+    }
+
+    #[test]
+    fn test_degree_of_empty_and_zero_polynomials() {
+        assert_eq!(DenseUnivariatePolynomial::<Fq>::new(vec![]).degree(), 0);
+        assert_eq!(
+            DenseUnivariatePolynomial::new(vec![fq(0), fq(0), fq(0)]).degree(),
+            0
+        );
+        assert_eq!(
+            DenseUnivariatePolynomial::new(vec![fq(1), fq(2), fq(0)]).degree(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_with_trailing_zeros() {
+        let padded = DenseUnivariatePolynomial::new(vec![fq(1), fq(0), fq(0)]);
+        let linear = DenseUnivariatePolynomial::new(vec![fq(1), fq(1)]);
+
+        assert_eq!(
+            (&padded + &linear).coefficients_slice(),
+            &[fq(2), fq(1), fq(0)]
+        );
+        assert_eq!(
+            (&padded * &linear).coefficients_slice(),
+            &[fq(1), fq(1), fq(0), fq(0)]
+        );
+    }
 }
@@ -0,0 +1,52 @@
+use ark_ff::PrimeField;
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterpolationError {
+    LengthMismatch { num_xs: usize, num_ys: usize },
+    DuplicateX,
+    DegreeMismatch { expected: usize, actual: usize },
+}
+
+impl fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpolationError::LengthMismatch { num_xs, num_ys } => write!(
+                f,
+                "xs and ys must have the same length, got {} xs and {} ys",
+                num_xs, num_ys
+            ),
+            InterpolationError::DuplicateX => {
+                write!(f, "interpolation points must have distinct x-coordinates")
+            }
+            InterpolationError::DegreeMismatch { expected, actual } => write!(
+                f,
+                "interpolated polynomial has degree {}, expected {}",
+                actual, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InterpolationError {}
+
+pub(crate) fn check_interpolation_points<F: PrimeField>(
+    xs: &[F],
+    ys: &[F],
+) -> Result<(), InterpolationError> {
+    if xs.len() != ys.len() {
+        return Err(InterpolationError::LengthMismatch {
+            num_xs: xs.len(),
+            num_ys: ys.len(),
+        });
+    }
+
+    let mut seen = HashSet::with_capacity(xs.len());
+
+    if !xs.iter().all(|x| seen.insert(x)) {
+        return Err(InterpolationError::DuplicateX);
+    }
+
+    Ok(())
+}
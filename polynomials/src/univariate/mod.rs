@@ -1,4 +1,6 @@
 pub mod dense;
+pub mod error;
 pub mod sparse;
 
 pub use dense::DenseUnivariatePolynomial;
+pub use error::InterpolationError;
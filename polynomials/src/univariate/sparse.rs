@@ -1,9 +1,18 @@
+use super::error::{InterpolationError, check_interpolation_points};
+use crate::display::{univariate_monomial, write_terms};
 use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use std::cmp::Ordering;
+use std::fmt;
 use std::iter::{Product, Sum};
-use std::ops::{Add, Mul};
+use std::ops::{Add, AddAssign, Mul};
 
-#[derive(Debug, Clone, PartialEq)]
+// building the power table costs one multiplication per exponent up to the degree, while
+// `pow` costs roughly two per bit of each exponent, so the table only pays off when the
+// degree isn't much larger than the number of terms
+const POWER_TABLE_MAX_DEGREE_PER_TERM: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct SparseUnivariatePolynomial<F: PrimeField> {
     terms: Vec<(F, usize)>,
 }
@@ -13,6 +22,15 @@ impl<F: PrimeField> SparseUnivariatePolynomial<F> {
         Self { terms }
     }
 
+    // no terms at all, so adding it leaves the terms of the other polynomial untouched
+    pub fn zero() -> Self {
+        Self::new(vec![])
+    }
+
+    pub fn one() -> Self {
+        Self::new(vec![(F::ONE, 0)])
+    }
+
     pub fn degree(&self) -> usize {
         match self.terms.iter().max_by_key(|&(_, exp)| exp) {
             Some((_, degree)) => *degree,
@@ -24,6 +42,12 @@ impl<F: PrimeField> SparseUnivariatePolynomial<F> {
         &self.terms
     }
 
+    // stored (coefficient, exponent) pairs, which may include zero coefficients or repeated
+    // exponents since `new` doesn't normalize them
+    pub fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
     pub fn scalar_mul(&self, scalar: F) -> Self {
         let new_terms = self
             .terms
@@ -55,13 +79,40 @@ impl<F: PrimeField> SparseUnivariatePolynomial<F> {
             .sum()
     }
 
+    pub fn evaluate_with_powers(&self, x: F) -> F {
+        let degree = self.degree();
+
+        if degree > POWER_TABLE_MAX_DEGREE_PER_TERM * self.terms.len() {
+            return self.evaluate(x);
+        }
+
+        let mut powers = Vec::with_capacity(degree + 1);
+        let mut current = F::ONE;
+
+        for _ in 0..=degree {
+            powers.push(current);
+            current *= x;
+        }
+
+        self.terms
+            .iter()
+            .map(|(coeff, exp)| coeff.mul(powers[*exp]))
+            .sum()
+    }
+
     pub fn interpolate(xs: &[F], ys: &[F]) -> Self {
-        assert_eq!(xs.len(), ys.len());
+        Self::try_interpolate(xs, ys).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    // duplicate xs leave the lagrange basis ill-defined, so they are rejected up front
+    pub fn try_interpolate(xs: &[F], ys: &[F]) -> Result<Self, InterpolationError> {
+        check_interpolation_points(xs, ys)?;
 
-        xs.iter()
+        Ok(xs
+            .iter()
             .zip(ys.iter())
             .map(|(x, y)| Self::basis(*x, xs).scalar_mul(*y))
-            .sum()
+            .sum())
     }
 }
 
@@ -142,6 +193,45 @@ impl<F: PrimeField> Add for &SparseUnivariatePolynomial<F> {
     }
 }
 
+// the merge of `+` done in place: the terms vector grows by rhs.len() once and is filled
+// from the back, so nothing is overwritten before it is read and no second vector is built.
+// like `+`, both sides are expected sorted by exponent
+impl<F: PrimeField> AddAssign<&SparseUnivariatePolynomial<F>> for SparseUnivariatePolynomial<F> {
+    fn add_assign(&mut self, rhs: &Self) {
+        let mut i = self.terms.len();
+        let mut j = rhs.terms.len();
+        let mut write = i + j;
+
+        self.terms.resize(write, (F::ZERO, 0));
+
+        while j > 0 {
+            let (coeff2, exp2) = rhs.terms[j - 1];
+            write -= 1;
+
+            match self.terms[..i].last() {
+                Some(&(coeff1, exp1)) if exp1 > exp2 => {
+                    self.terms[write] = (coeff1, exp1);
+                    i -= 1;
+                }
+                Some(&(coeff1, exp1)) if exp1 == exp2 => {
+                    self.terms[write] = (coeff1 + coeff2, exp1);
+                    i -= 1;
+                    j -= 1;
+                }
+                _ => {
+                    self.terms[write] = (coeff2, exp2);
+                    j -= 1;
+                }
+            }
+        }
+
+        // what is left of self is already in place up to the gap that combined exponents opened
+        self.terms.copy_within(..i, write - i);
+        self.terms.drain(..write - i);
+        self.terms.retain(|&(coeff, _)| coeff != F::ZERO);
+    }
+}
+
 impl<F: PrimeField> Mul for &SparseUnivariatePolynomial<F> {
     type Output = SparseUnivariatePolynomial<F>;
 
@@ -154,16 +244,45 @@ impl<F: PrimeField> Mul for &SparseUnivariatePolynomial<F> {
             }
         }
 
+        // combine like terms, otherwise a product of k binomials keeps all 2^k pairwise terms
+        // (e.g. the basis polynomials of `interpolate`). sorted by exponent like `+` expects
+        result.sort_unstable_by_key(|&(_, exp)| exp);
+        result.dedup_by(|(coeff, exp), (acc_coeff, acc_exp)| {
+            if exp == acc_exp {
+                *acc_coeff += *coeff;
+                true
+            } else {
+                false
+            }
+        });
+        result.retain(|&(coeff, _)| coeff != F::ZERO);
+
         SparseUnivariatePolynomial { terms: result }
     }
 }
 
+impl<F: PrimeField> Mul<F> for SparseUnivariatePolynomial<F> {
+    type Output = SparseUnivariatePolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &SparseUnivariatePolynomial<F> {
+    type Output = SparseUnivariatePolynomial<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        self.scalar_mul(rhs)
+    }
+}
+
 impl<F: PrimeField> Sum for SparseUnivariatePolynomial<F> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         let mut result = SparseUnivariatePolynomial::new(vec![(F::ZERO, 0)]);
 
         for poly in iter {
-            result = &result + &poly;
+            result += &poly;
         }
 
         result
@@ -172,7 +291,7 @@ impl<F: PrimeField> Sum for SparseUnivariatePolynomial<F> {
 
 impl<F: PrimeField> Product for SparseUnivariatePolynomial<F> {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        let mut result = SparseUnivariatePolynomial::new(vec![(F::ONE, 0)]);
+        let mut result = SparseUnivariatePolynomial::one();
 
         for poly in iter {
             result = &result * &poly
@@ -182,13 +301,29 @@ impl<F: PrimeField> Product for SparseUnivariatePolynomial<F> {
     }
 }
 
+impl<F: PrimeField> fmt::Display for SparseUnivariatePolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut terms = self.terms.clone();
+        terms.sort_by_key(|&(_, exp)| std::cmp::Reverse(exp));
+
+        write_terms(
+            f,
+            terms
+                .into_iter()
+                .map(|(coeff, exp)| (coeff, univariate_monomial(exp))),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helpers::{assert_mul_by_scalar, assert_zero_and_one_are_identities};
+    use crate::util::signed;
     use ark_bls12_381::Fq;
 
     fn fq(x: i64) -> Fq {
-        Fq::from(x)
+        signed(x)
     }
 
     fn test_poly() -> SparseUnivariatePolynomial<Fq> {
@@ -197,6 +332,75 @@ mod tests {
         SparseUnivariatePolynomial::new(coeffs)
     }
 
+    #[test]
+    fn test_terms() {
+        let poly = test_poly();
+
+        assert_eq!(poly.terms_slice(), &[(fq(1), 0), (fq(2), 1), (fq(3), 2)]);
+        assert_eq!(poly.num_terms(), 3);
+        assert_eq!(SparseUnivariatePolynomial::<Fq>::zero().num_terms(), 0);
+    }
+
+    #[test]
+    fn test_add_assign_matches_add() {
+        let polys = vec![
+            test_poly(),
+            SparseUnivariatePolynomial::new(vec![(fq(4), 1), (fq(-3), 2), (fq(5), 6)]),
+            SparseUnivariatePolynomial::new(vec![(fq(-1), 0), (fq(7), 3)]),
+        ];
+
+        let added = polys
+            .iter()
+            .fold(SparseUnivariatePolynomial::zero(), |acc, poly| &acc + poly);
+        let summed: SparseUnivariatePolynomial<Fq> = polys.into_iter().sum();
+
+        assert_eq!(summed, added);
+        assert_eq!(summed.terms_slice(), &[(fq(6), 1), (fq(7), 3), (fq(5), 6)]);
+
+        let mut cancelled = test_poly();
+        cancelled += &test_poly().scalar_mul(fq(-1));
+
+        assert_eq!(cancelled, SparseUnivariatePolynomial::zero());
+
+        // merged exponents at the top leave the lower terms of self to be shifted up
+        let lhs = SparseUnivariatePolynomial::new(vec![(fq(1), 0), (fq(2), 1), (fq(3), 5)]);
+        let rhs = SparseUnivariatePolynomial::new(vec![(fq(4), 5), (fq(6), 7)]);
+        let mut accumulated = lhs.clone();
+        accumulated += &rhs;
+
+        assert_eq!(accumulated, &lhs + &rhs);
+        assert_eq!(
+            accumulated.terms_slice(),
+            &[(fq(1), 0), (fq(2), 1), (fq(7), 5), (fq(6), 7)]
+        );
+
+        // an empty sum is still the single zero term
+        assert_eq!(
+            std::iter::empty::<SparseUnivariatePolynomial<Fq>>()
+                .sum::<SparseUnivariatePolynomial<Fq>>()
+                .terms_slice(),
+            &[(fq(0), 0)]
+        );
+    }
+
+    #[test]
+    fn test_zero_and_one_are_identities() {
+        assert_zero_and_one_are_identities(
+            &test_poly(),
+            &SparseUnivariatePolynomial::zero(),
+            &SparseUnivariatePolynomial::one(),
+            |a, b| a + b,
+            |a, b| a * b,
+        );
+    }
+
+    #[test]
+    fn test_mul_by_scalar() {
+        let poly = SparseUnivariatePolynomial::new(vec![(fq(1), 0), (fq(2), 1), (fq(3), 4)]);
+
+        assert_mul_by_scalar(&poly, fq(7), &poly.scalar_mul(fq(7)));
+    }
+
     #[test]
     fn test_degree() {
         // f(x) = 1 + 2x + 3x^2
@@ -259,6 +463,22 @@ mod tests {
         assert_eq!(&poly_1 * &poly_2, expected_result);
     }
 
+    #[test]
+    fn test_multiplication_combines_like_terms() {
+        // (x - 1)(x + 1) = x^2 - 1, the two x terms cancel
+        let product = &SparseUnivariatePolynomial::new(vec![(fq(-1), 0), (fq(1), 1)])
+            * &SparseUnivariatePolynomial::new(vec![(fq(1), 0), (fq(1), 1)]);
+
+        assert_eq!(product.terms_slice(), &[(fq(-1), 0), (fq(1), 2)]);
+
+        let xs: Vec<Fq> = (1..=20).map(fq).collect();
+
+        assert_eq!(
+            SparseUnivariatePolynomial::basis(fq(1), &xs).num_terms(),
+            20
+        );
+    }
+
     #[test]
     fn test_interpolation() {
         // f(x) = 2x
@@ -270,4 +490,70 @@ mod tests {
 
         assert_eq!(interpolated_poly, expected_result);
     }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(test_poly().to_string(), "3x^2 + 2x + 1");
+        assert_eq!(
+            SparseUnivariatePolynomial::new(vec![(fq(-2), 0), (fq(1), 4)]).to_string(),
+            "x^4 - 2"
+        );
+    }
+
+    #[test]
+    fn test_evaluate_with_powers() {
+        let poly = test_poly();
+
+        assert_eq!(poly.evaluate_with_powers(fq(2)), fq(17));
+        assert_eq!(
+            SparseUnivariatePolynomial::<Fq>::new(vec![]).evaluate_with_powers(fq(2)),
+            fq(0)
+        );
+
+        // degree far above the threshold takes the `pow` fallback
+        let sparse_high_degree =
+            SparseUnivariatePolynomial::new(vec![(fq(3), 1 << 20), (fq(1), 0)]);
+
+        assert_eq!(
+            sparse_high_degree.evaluate_with_powers(fq(2)),
+            sparse_high_degree.evaluate(fq(2))
+        );
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let poly = test_poly();
+        let mut bytes = vec![];
+        poly.serialize_compressed(&mut bytes).unwrap();
+
+        assert_eq!(
+            SparseUnivariatePolynomial::deserialize_compressed(&bytes[..]).unwrap(),
+            poly
+        );
+    }
+
+    #[test]
+    fn test_try_interpolate() {
+        assert_eq!(
+            SparseUnivariatePolynomial::try_interpolate(&[fq(2), fq(2)], &[fq(4), fq(8)]),
+            Err(InterpolationError::DuplicateX)
+        );
+        assert_eq!(
+            SparseUnivariatePolynomial::try_interpolate(&[fq(2), fq(4)], &[fq(4)]),
+            Err(InterpolationError::LengthMismatch {
+                num_xs: 2,
+                num_ys: 1
+            })
+        );
+        assert_eq!(
+            SparseUnivariatePolynomial::try_interpolate(&[fq(2), fq(4)], &[fq(4), fq(8)]).unwrap(),
+            SparseUnivariatePolynomial::interpolate(&[fq(2), fq(4)], &[fq(4), fq(8)])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation points must have distinct x-coordinates")]
+    fn test_interpolate_duplicate_x_panics() {
+        SparseUnivariatePolynomial::interpolate(&[fq(2), fq(2)], &[fq(4), fq(8)]);
+    }
 }
@@ -0,0 +1,105 @@
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::CanonicalDeserialize;
+use std::fmt;
+
+// maps negative integers to p - |x| explicitly instead of relying on the i64 conversions of
+// a particular field type
+pub fn signed<F: PrimeField>(x: i64) -> F {
+    let abs = F::from(x.unsigned_abs());
+
+    if x < 0 { -abs } else { abs }
+}
+
+// canonical encoding of a field element in the given byte order, always the full width of an
+// `F::BigInt`. big-endian is what `to_bytes` and the transcripts use
+pub fn field_to_bytes<F: PrimeField>(element: &F, little_endian: bool) -> Vec<u8> {
+    let bigint = element.into_bigint();
+
+    if little_endian {
+        bigint.to_bytes_le()
+    } else {
+        bigint.to_bytes_be()
+    }
+}
+
+// width in bytes of every `field_to_bytes` encoding
+pub fn field_byte_size<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize).div_ceil(64) * 8
+}
+
+// inverse of `field_to_bytes`. None for the wrong width or a value at or above the modulus,
+// which is rejected rather than reduced
+pub fn field_from_bytes<F: PrimeField>(bytes: &[u8], little_endian: bool) -> Option<F> {
+    if bytes.len() != field_byte_size::<F>() {
+        return None;
+    }
+
+    // the limbs of a BigInt deserialize little-endian
+    let le_bytes: Vec<u8> = if little_endian {
+        bytes.to_vec()
+    } else {
+        bytes.iter().rev().copied().collect()
+    };
+
+    F::BigInt::deserialize_uncompressed(&le_bytes[..])
+        .ok()
+        .and_then(F::from_bigint)
+}
+
+// errors of the `from_bytes` decoders built on `field_from_bytes`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    LengthNotMultipleOfElementSize { len: usize, element_size: usize },
+    NotPowerOfTwo { num_evals: usize },
+    NonCanonicalElement { index: usize },
+    NonCanonicalCoefficient { index: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::LengthNotMultipleOfElementSize { len, element_size } => write!(
+                f,
+                "byte length {} is not a multiple of the {}-byte field element size",
+                len, element_size
+            ),
+            DecodeError::NotPowerOfTwo { num_evals } => write!(
+                f,
+                "number of evaluations must be a power of two, got {}",
+                num_evals
+            ),
+            DecodeError::NonCanonicalElement { index } => write!(
+                f,
+                "evaluation {} is not a canonical field element encoding",
+                index
+            ),
+            DecodeError::NonCanonicalCoefficient { index } => write!(
+                f,
+                "coefficient {} is not a canonical field element encoding",
+                index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::{AdditiveGroup, BigInteger, Field};
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(signed::<Fq>(-1) + Fq::ONE, Fq::ZERO);
+        assert_eq!(signed::<Fq>(7), Fq::from(7u64));
+        assert_eq!(signed::<Fq>(-3) + signed::<Fq>(3), Fq::ZERO);
+
+        let mut expected = Fq::MODULUS;
+        expected.sub_with_borrow(&5u64.into());
+
+        assert_eq!(signed::<Fq>(-5).into_bigint(), expected);
+        assert_eq!(signed::<Fq>(i64::MIN), -Fq::from(1u64 << 63));
+    }
+}
@@ -0,0 +1,52 @@
+use polynomials::univariate::InterpolationError;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    NotEnoughPositions {
+        needed: usize,
+        got: usize,
+    },
+    PositionOutOfRange {
+        position: usize,
+        codeword_len: usize,
+    },
+    DuplicatePosition {
+        position: usize,
+    },
+    InconsistentCodeword {
+        position: usize,
+    },
+    Interpolation(InterpolationError),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::NotEnoughPositions { needed, got } => write!(
+                f,
+                "decoding needs at least {} codeword positions, got {}",
+                needed, got
+            ),
+            DecodeError::PositionOutOfRange {
+                position,
+                codeword_len,
+            } => write!(
+                f,
+                "codeword position {} is out of range for a codeword of length {}",
+                position, codeword_len
+            ),
+            DecodeError::DuplicatePosition { position } => {
+                write!(f, "codeword position {} is given more than once", position)
+            }
+            DecodeError::InconsistentCodeword { position } => write!(
+                f,
+                "the value at codeword position {} is not on the decoded polynomial",
+                position
+            ),
+            DecodeError::Interpolation(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
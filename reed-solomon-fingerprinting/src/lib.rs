@@ -1,14 +1,25 @@
+pub mod error;
+
 use ark_ff::PrimeField;
+pub use error::DecodeError;
+use polynomials::{domain::radix2_domain, univariate::DenseUnivariatePolynomial};
+use transcript::util::ConstantTimeFieldEq;
 
 pub struct ReedSolomonFingerprint<F: PrimeField> {
     r: F,
     v: F,
 }
 
+// the fingerprint is the data polynomial of degree data.len() - 1 evaluated at a random r, so
+// two different inputs collide with probability at most (data.len() - 1)/|F| < data.len()/|F|.
+// that is only meaningful while the degree stays below |F|: from degree |F| on, x^|F| - x is a
+// non-zero polynomial that vanishes on the whole field. so data.len() <= |F| is the hard
+// bound, and soundness needs data.len() to be far below it.
 pub fn hash<F: PrimeField>(data_a: &[F]) -> ReedSolomonFingerprint<F> {
     assert!(
-        F::MODULUS.gt(&F::BigInt::from(data_a.len() as u64)),
-        "Length of input data is greater than modulus of the prime field."
+        F::MODULUS >= F::BigInt::from(data_a.len() as u64),
+        "Data polynomial degree must be below the field size, but {} elements were given",
+        data_a.len()
     );
 
     let mut rng = rand::thread_rng();
@@ -32,12 +43,106 @@ pub fn verify<F: PrimeField>(data_b: &[F], rsf: ReedSolomonFingerprint<F>) -> bo
     rsf.v == eval_b
 }
 
+pub fn verify_ct<F: PrimeField>(data_b: &[F], rsf: ReedSolomonFingerprint<F>) -> bool {
+    let eval_b: F = data_b
+        .iter()
+        .enumerate()
+        .map(|(index, x)| *x * rsf.r.pow([index as u64]))
+        .sum();
+
+    rsf.v.ct_eq(&eval_b)
+}
+
+// low-degree extension of the message: the message is read as evaluations over the
+// message.len()-th roots of unity and the interpolant is evaluated over the
+// (rate_inv * message.len())-th roots of unity. the small domain is every rate_inv-th
+// element of the large one, so the codeword holds the message at positions i * rate_inv.
+pub fn encode<F: PrimeField>(message: &[F], rate_inv: usize) -> Vec<F> {
+    assert!(
+        message.len().is_power_of_two() && rate_inv.is_power_of_two(),
+        "Message length and inverse rate must be powers of two"
+    );
+
+    let message_domain = radix2_domain::<F>(message.len().ilog2() as usize);
+    let poly = DenseUnivariatePolynomial::interpolate(&message_domain, message);
+
+    radix2_domain::<F>((message.len() * rate_inv).ilog2() as usize)
+        .into_iter()
+        .map(|x| poly.evaluate(x))
+        .collect()
+}
+
+// recovers the message from any message_len (position, value) pairs of an erasure-only
+// codeword, i.e. the given values are assumed to be correct. the first message_len points fix
+// the polynomial and every further point has to lie on it
+pub fn decode<F: PrimeField>(
+    codeword_points: &[(usize, F)],
+    message_len: usize,
+    rate_inv: usize,
+) -> Result<Vec<F>, DecodeError> {
+    assert!(
+        message_len.is_power_of_two() && rate_inv.is_power_of_two(),
+        "Message length and inverse rate must be powers of two"
+    );
+
+    if codeword_points.len() < message_len {
+        return Err(DecodeError::NotEnoughPositions {
+            needed: message_len,
+            got: codeword_points.len(),
+        });
+    }
+
+    let codeword_len = message_len * rate_inv;
+    let mut seen = vec![false; codeword_len];
+
+    for &(position, _) in codeword_points {
+        if position >= codeword_len {
+            return Err(DecodeError::PositionOutOfRange {
+                position,
+                codeword_len,
+            });
+        }
+
+        if std::mem::replace(&mut seen[position], true) {
+            return Err(DecodeError::DuplicatePosition { position });
+        }
+    }
+
+    let codeword_domain = radix2_domain::<F>(codeword_len.ilog2() as usize);
+    let (interpolating_points, extra_points) = codeword_points.split_at(message_len);
+    let (xs, ys): (Vec<F>, Vec<F>) = interpolating_points
+        .iter()
+        .map(|&(position, value)| (codeword_domain[position], value))
+        .unzip();
+    let poly =
+        DenseUnivariatePolynomial::try_interpolate(&xs, &ys).map_err(DecodeError::Interpolation)?;
+
+    if let Some(&(position, _)) = extra_points
+        .iter()
+        .find(|&&(position, value)| poly.evaluate(codeword_domain[position]) != value)
+    {
+        return Err(DecodeError::InconsistentCodeword { position });
+    }
+
+    Ok(radix2_domain::<F>(message_len.ilog2() as usize)
+        .into_iter()
+        .map(|x| poly.evaluate(x))
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{hash, verify};
-    use ark_bls12_381::Fq;
+    use crate::{DecodeError, decode, encode, hash, verify, verify_ct};
+    use ark_bls12_381::{Fq, Fr};
+    use ark_ff::{Fp64, MontBackend, MontConfig};
     use rand::Rng;
 
+    #[derive(MontConfig)]
+    #[modulus = "17"]
+    #[generator = "3"]
+    struct F17Config;
+    type F17 = Fp64<MontBackend<F17Config, 1>>;
+
     fn fq(val: u64) -> Fq {
         Fq::from(val)
     }
@@ -80,4 +185,90 @@ mod tests {
         let fingerprint = hash(&data);
         assert!(verify(&data, fingerprint));
     }
+
+    #[test]
+    fn test_fingerprint_verify_ct() {
+        let data_a = vec![fq(1), fq(2), fq(3), fq(4), fq(5)];
+        let mut data_b = data_a.clone();
+        data_b[2] = fq(100);
+
+        assert!(verify_ct(&data_a, hash(&data_a)));
+        assert!(!verify_ct(&data_b, hash(&data_a)));
+    }
+
+    #[test]
+    fn test_fingerprint_at_field_size() {
+        // 17 elements give a degree 16 data polynomial, the largest degree below |F17|
+        let data: Vec<F17> = (0..17u64).map(F17::from).collect();
+
+        assert!(verify(&data, hash(&data)));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Data polynomial degree must be below the field size, but 18 elements were given"
+    )]
+    fn test_fingerprint_above_field_size() {
+        let data: Vec<F17> = (0..18u64).map(F17::from).collect();
+
+        hash(&data);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let message: Vec<Fr> = [3u64, 1, 4, 1, 5, 9, 2, 6].map(Fr::from).to_vec();
+        let codeword = encode(&message, 4);
+
+        assert_eq!(codeword.len(), 32);
+
+        for (i, value) in message.iter().enumerate() {
+            assert_eq!(codeword[i * 4], *value);
+        }
+
+        let positions: Vec<(usize, Fr)> = [31, 2, 17, 5, 22, 9, 13, 30]
+            .iter()
+            .map(|&position| (position, codeword[position]))
+            .collect();
+
+        assert_eq!(decode(&positions, message.len(), 4), Ok(message.clone()));
+
+        let mut with_extra = positions.clone();
+        with_extra.push((0, codeword[0]));
+
+        assert_eq!(decode(&with_extra, message.len(), 4), Ok(message));
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_positions() {
+        let message: Vec<Fr> = (0..8u64).map(Fr::from).collect();
+        let codeword = encode(&message, 2);
+        let mut positions: Vec<(usize, Fr)> = (0..8).map(|i| (i, codeword[i])).collect();
+
+        assert_eq!(
+            decode(&positions[..2], message.len(), 2),
+            Err(DecodeError::NotEnoughPositions { needed: 8, got: 2 })
+        );
+
+        positions[3] = (16, codeword[3]);
+        assert_eq!(
+            decode(&positions, message.len(), 2),
+            Err(DecodeError::PositionOutOfRange {
+                position: 16,
+                codeword_len: 16
+            })
+        );
+
+        positions[3] = (2, codeword[2]);
+        assert_eq!(
+            decode(&positions, message.len(), 2),
+            Err(DecodeError::DuplicatePosition { position: 2 })
+        );
+
+        positions[3] = (3, codeword[3]);
+        positions.push((9, codeword[9] + Fr::from(1u64)));
+        assert_eq!(
+            decode(&positions, message.len(), 2),
+            Err(DecodeError::InconsistentCodeword { position: 9 })
+        );
+    }
 }
@@ -12,7 +12,7 @@ pub fn sss_benchmarks(c: &mut Criterion) {
             let secret = Fq::rand(&mut rng);
             let num_shares = 100;
             let threshold = 50;
-            black_box(shares(secret, num_shares, threshold));
+            black_box(shares(secret, num_shares, threshold).unwrap());
         })
     });
 
@@ -21,8 +21,8 @@ pub fn sss_benchmarks(c: &mut Criterion) {
             let secret = Fq::rand(&mut rng);
             let num_shares = 100;
             let threshold = 50;
-            let shares = shares(secret, num_shares, threshold);
-            black_box(recover_secret(shares));
+            let shares = shares(secret, num_shares, threshold).unwrap();
+            black_box(recover_secret(shares).unwrap());
         })
     });
 }
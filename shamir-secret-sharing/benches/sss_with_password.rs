@@ -13,7 +13,7 @@ pub fn sss_with_password_benchmarks(c: &mut Criterion) {
             let password = Fq::from(299792458);
             let num_shares = 100;
             let threshold = 50;
-            black_box(shares(secret, password, num_shares, threshold));
+            black_box(shares(secret, password, num_shares, threshold).unwrap());
         })
     });
 
@@ -23,8 +23,8 @@ pub fn sss_with_password_benchmarks(c: &mut Criterion) {
             let password = Fq::from(299792458);
             let num_shares = 100;
             let threshold = 50;
-            let shares = shares(secret, password, num_shares, threshold);
-            black_box(recover_secret(shares, password));
+            let shares = shares(secret, password, num_shares, threshold).unwrap();
+            black_box(recover_secret(shares, password).unwrap());
         })
     });
 }
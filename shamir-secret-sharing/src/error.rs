@@ -0,0 +1,65 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SssError {
+    InvalidThreshold { threshold: u64, num_shares: u64 },
+    PasswordCollision,
+    EmptyShares,
+    DuplicateShareIndex,
+    ShareLengthMismatch,
+}
+
+impl fmt::Display for SssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SssError::InvalidThreshold {
+                threshold,
+                num_shares,
+            } => write!(
+                f,
+                "threshold must satisfy 0 < threshold <= num_shares, got threshold {} with {} shares",
+                threshold, num_shares
+            ),
+            SssError::PasswordCollision => {
+                write!(f, "password must not coincide with a share x-coordinate")
+            }
+            SssError::EmptyShares => write!(f, "at least one share is needed for recovery"),
+            SssError::DuplicateShareIndex => {
+                write!(f, "shares must have distinct x-coordinates")
+            }
+            SssError::ShareLengthMismatch => {
+                write!(
+                    f,
+                    "vector shares must all hold the same number of evaluations"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SssError {}
+
+pub(crate) fn check_threshold(num_shares: u64, threshold: u64) -> Result<(), SssError> {
+    if threshold == 0 || threshold > num_shares {
+        return Err(SssError::InvalidThreshold {
+            threshold,
+            num_shares,
+        });
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_shares<F: PartialEq, Y>(shares: &[(F, Y)]) -> Result<(), SssError> {
+    if shares.is_empty() {
+        return Err(SssError::EmptyShares);
+    }
+
+    for (i, (x, _)) in shares.iter().enumerate() {
+        if shares[i + 1..].iter().any(|(other, _)| other == x) {
+            return Err(SssError::DuplicateShareIndex);
+        }
+    }
+
+    Ok(())
+}
@@ -1,2 +1,5 @@
+pub mod error;
 pub mod sss;
 pub mod sss_with_password;
+
+pub use error::SssError;
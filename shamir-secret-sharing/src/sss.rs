@@ -1,25 +1,44 @@
+use crate::error::{SssError, check_shares, check_threshold};
 use ark_ff::PrimeField;
 use polynomials::univariate::dense::DenseUnivariatePolynomial;
+use rand::Rng;
+
+pub fn shares<F: PrimeField>(
+    secret: F,
+    num_shares: u64,
+    threshold: u64,
+) -> Result<Vec<(F, F)>, SssError> {
+    shares_from_rng(secret, num_shares, threshold, &mut rand::thread_rng())
+}
+
+// same as `shares` with the polynomial sampled from the given rng, so a seeded rng lets a
+// dealer reproduce the exact share set
+pub fn shares_from_rng<F: PrimeField, R: Rng>(
+    secret: F,
+    num_shares: u64,
+    threshold: u64,
+    rng: &mut R,
+) -> Result<Vec<(F, F)>, SssError> {
+    check_threshold(num_shares, threshold)?;
 
-pub fn shares<F: PrimeField>(secret: F, num_shares: u64, threshold: u64) -> Vec<(F, F)> {
     let mut shares: Vec<(F, F)> = Vec::new();
-    let mut rng = rand::thread_rng();
-    let mut coeffs = (1..threshold)
-        .map(|_| F::rand(&mut rng))
-        .collect::<Vec<F>>();
+    let mut coeffs = (1..threshold).map(|_| F::rand(rng)).collect::<Vec<F>>();
 
     coeffs.splice(0..0, [secret]);
 
     let poly = DenseUnivariatePolynomial::new(coeffs);
 
-    for i in 1..num_shares {
+    // x = 1..=num_shares, the same points as `shares_vec`
+    for i in 1..=num_shares {
         shares.push((F::from(i), poly.evaluate(F::from(i))));
     }
 
-    shares
+    Ok(shares)
 }
 
-pub fn recover_secret<F: PrimeField>(shares: Vec<(F, F)>) -> F {
+pub fn recover_secret<F: PrimeField>(shares: Vec<(F, F)>) -> Result<F, SssError> {
+    check_shares(&shares)?;
+
     let mut xs: Vec<F> = Vec::new();
     let mut ys: Vec<F> = Vec::new();
 
@@ -30,7 +49,65 @@ pub fn recover_secret<F: PrimeField>(shares: Vec<(F, F)>) -> F {
 
     let poly = DenseUnivariatePolynomial::interpolate(&xs, &ys);
 
-    poly.evaluate(F::from(0))
+    Ok(poly.evaluate(F::from(0)))
+}
+
+// shares every secret with its own random polynomial, but evaluates them all at the same
+// x-coordinates, so each share is one x with the vector of evaluations at that x
+pub fn shares_vec<F: PrimeField>(
+    secrets: &[F],
+    num_shares: u64,
+    threshold: u64,
+) -> Result<Vec<(F, Vec<F>)>, SssError> {
+    check_threshold(num_shares, threshold)?;
+
+    let mut rng = rand::thread_rng();
+    let polys: Vec<DenseUnivariatePolynomial<F>> = secrets
+        .iter()
+        .map(|&secret| {
+            let coeffs = std::iter::once(secret)
+                .chain((1..threshold).map(|_| F::rand(&mut rng)))
+                .collect();
+
+            DenseUnivariatePolynomial::new(coeffs)
+        })
+        .collect();
+
+    Ok((1..=num_shares)
+        .map(|i| {
+            let x = F::from(i);
+
+            (x, polys.iter().map(|poly| poly.evaluate(x)).collect())
+        })
+        .collect())
+}
+
+// the lagrange basis at zero only depends on the xs, so it's computed once and reused for
+// every secret
+pub fn recover_vec<F: PrimeField>(shares: Vec<(F, Vec<F>)>) -> Result<Vec<F>, SssError> {
+    check_shares(&shares)?;
+
+    let num_secrets = shares[0].1.len();
+
+    if shares.iter().any(|(_, ys)| ys.len() != num_secrets) {
+        return Err(SssError::ShareLengthMismatch);
+    }
+
+    let xs: Vec<F> = shares.iter().map(|(x, _)| *x).collect();
+    let basis_at_zero: Vec<F> = xs
+        .iter()
+        .map(|&x| DenseUnivariatePolynomial::basis(x, &xs).evaluate(F::ZERO))
+        .collect();
+
+    Ok((0..num_secrets)
+        .map(|k| {
+            shares
+                .iter()
+                .zip(&basis_at_zero)
+                .map(|((_, ys), &basis)| ys[k] * basis)
+                .sum()
+        })
+        .collect())
 }
 
 #[cfg(test)]
@@ -44,23 +121,119 @@ mod tests {
         let threshold = 4;
         let num_of_shares = 10;
 
-        let shares = shares(secret, num_of_shares, threshold);
+        let shares = shares(secret, num_of_shares, threshold).unwrap();
 
-        let recovered_secret = recover_secret(shares);
+        let recovered_secret = recover_secret(shares).unwrap();
 
         assert_eq!(recovered_secret, secret);
     }
 
+    #[test]
+    fn test_shares_hands_out_num_shares() {
+        let secret = Fq::from(1729);
+        let shares = shares(secret, 5, 5).unwrap();
+
+        assert_eq!(
+            shares.iter().map(|(x, _)| *x).collect::<Vec<_>>(),
+            (1..=5).map(Fq::from).collect::<Vec<_>>()
+        );
+        assert_eq!(recover_secret(shares.clone()).unwrap(), secret);
+        assert_ne!(recover_secret(shares[1..].to_vec()).unwrap(), secret);
+    }
+
     #[test]
     fn test_recover_wrong_secret_fails() {
         let secret = Fq::from(220284);
         let threshold = 4;
         let num_of_shares = 10;
 
-        let shares = shares(secret, num_of_shares, threshold);
+        let shares = shares(secret, num_of_shares, threshold).unwrap();
 
-        let recovered_secret = recover_secret(shares);
+        let recovered_secret = recover_secret(shares).unwrap();
 
         assert_ne!(recovered_secret, Fq::from(10));
     }
+
+    #[test]
+    fn test_zero_threshold_errors() {
+        assert_eq!(
+            shares(Fq::from(1729), 10, 0),
+            Err(SssError::InvalidThreshold {
+                threshold: 0,
+                num_shares: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_threshold_above_num_shares_errors() {
+        assert_eq!(
+            shares(Fq::from(1729), 3, 4),
+            Err(SssError::InvalidThreshold {
+                threshold: 4,
+                num_shares: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_recover_from_empty_shares_errors() {
+        assert_eq!(recover_secret::<Fq>(vec![]), Err(SssError::EmptyShares));
+    }
+
+    #[test]
+    fn test_recover_from_duplicate_shares_errors() {
+        let shares = vec![(Fq::from(1), Fq::from(5)), (Fq::from(1), Fq::from(5))];
+
+        assert_eq!(recover_secret(shares), Err(SssError::DuplicateShareIndex));
+    }
+
+    #[test]
+    fn test_recover_secret_vector() {
+        let secrets = vec![Fq::from(1729), Fq::from(42), Fq::from(7)];
+
+        let shares = shares_vec(&secrets, 6, 4).unwrap();
+
+        assert_eq!(shares.len(), 6);
+        assert!(shares.iter().all(|(_, ys)| ys.len() == 3));
+        assert_eq!(recover_vec(shares[2..].to_vec()).unwrap(), secrets);
+        assert_ne!(recover_vec(shares[3..].to_vec()).unwrap(), secrets);
+    }
+
+    #[test]
+    fn test_recover_secret_vector_with_threshold_equal_to_num_shares() {
+        let secrets = vec![Fq::from(1729), Fq::from(42)];
+        let shares = shares_vec(&secrets, 5, 5).unwrap();
+
+        assert_eq!(
+            shares.iter().map(|(x, _)| *x).collect::<Vec<_>>(),
+            (1..=5).map(Fq::from).collect::<Vec<_>>()
+        );
+        assert_eq!(recover_vec(shares.clone()).unwrap(), secrets);
+        assert_ne!(recover_vec(shares[1..].to_vec()).unwrap(), secrets);
+    }
+
+    #[test]
+    fn test_recover_vec_mismatched_lengths_errors() {
+        let shares = vec![
+            (Fq::from(1), vec![Fq::from(5), Fq::from(6)]),
+            (Fq::from(2), vec![Fq::from(5)]),
+        ];
+
+        assert_eq!(recover_vec(shares), Err(SssError::ShareLengthMismatch));
+    }
+
+    #[test]
+    fn test_shares_from_seeded_rng_are_reproducible() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let secret = Fq::from(1729);
+        let first = shares_from_rng(secret, 10, 4, &mut StdRng::seed_from_u64(7)).unwrap();
+        let second = shares_from_rng(secret, 10, 4, &mut StdRng::seed_from_u64(7)).unwrap();
+        let other_seed = shares_from_rng(secret, 10, 4, &mut StdRng::seed_from_u64(8)).unwrap();
+
+        assert_eq!(first, second);
+        assert_ne!(first, other_seed);
+        assert_eq!(recover_secret(first).unwrap(), secret);
+    }
 }
@@ -1,37 +1,63 @@
+use crate::error::{SssError, check_shares, check_threshold};
 use ark_ff::PrimeField;
 use polynomials::univariate::dense::DenseUnivariatePolynomial;
+use rand::Rng;
 
 pub fn shares<F: PrimeField>(
     secret: F,
     password: F,
     num_shares: u64,
     threshold: u64,
-) -> Vec<(F, F)> {
-    let mut shares: Vec<(F, F)> = Vec::new();
-    let mut rng = rand::thread_rng();
+) -> Result<Vec<(F, F)>, SssError> {
+    shares_from_rng(
+        secret,
+        password,
+        num_shares,
+        threshold,
+        &mut rand::thread_rng(),
+    )
+}
 
-    loop {
-        let (mut xs, mut ys) = (1..threshold)
-            .map(|i| (F::from(i), F::rand(&mut rng)))
-            .unzip::<_, _, Vec<F>, Vec<F>>();
+// same as `shares` with the polynomial sampled from the given rng, so a seeded rng
+// reproduces the exact share set
+pub fn shares_from_rng<F: PrimeField, R: Rng>(
+    secret: F,
+    password: F,
+    num_shares: u64,
+    threshold: u64,
+    rng: &mut R,
+) -> Result<Vec<(F, F)>, SssError> {
+    check_threshold(num_shares, threshold)?;
 
-        xs.splice(0..0, [password]);
-        ys.splice(0..0, [secret]);
+    if (1..=num_shares).any(|i| F::from(i) == password) {
+        return Err(SssError::PasswordCollision);
+    }
 
-        let poly = DenseUnivariatePolynomial::interpolate(&xs, &ys);
+    // p(x) = secret + (x - password) * q(x) hits the secret at x = password, and a non-zero
+    // leading coefficient of q makes the degree exactly threshold - 1 without resampling
+    // the whole polynomial
+    let mut q_coeffs: Vec<F> = (1..threshold).map(|_| F::rand(rng)).collect();
 
-        if poly.degree() == (threshold - 1) as usize {
-            for i in 1..num_shares {
-                shares.push((F::from(i), poly.evaluate(F::from(i))));
-            }
-            break;
+    if let Some(leading_coeff) = q_coeffs.last_mut() {
+        while leading_coeff.is_zero() {
+            *leading_coeff = F::rand(rng);
         }
     }
 
-    shares
+    let vanishing_at_password = DenseUnivariatePolynomial::new(vec![-password, F::ONE]);
+    let poly = &(&vanishing_at_password * &DenseUnivariatePolynomial::new(q_coeffs))
+        + &DenseUnivariatePolynomial::new(vec![secret]);
+
+    let shares = (1..=num_shares)
+        .map(|i| (F::from(i), poly.evaluate(F::from(i))))
+        .collect();
+
+    Ok(shares)
 }
 
-pub fn recover_secret<F: PrimeField>(shares: Vec<(F, F)>, password: F) -> F {
+pub fn recover_secret<F: PrimeField>(shares: Vec<(F, F)>, password: F) -> Result<F, SssError> {
+    check_shares(&shares)?;
+
     let mut xs: Vec<F> = Vec::new();
     let mut ys: Vec<F> = Vec::new();
 
@@ -42,7 +68,8 @@ pub fn recover_secret<F: PrimeField>(shares: Vec<(F, F)>, password: F) -> F {
 
     let poly = DenseUnivariatePolynomial::interpolate(&xs, &ys);
 
-    poly.evaluate(password)
+    // the secret sits at x = password, the same x-coordinate `shares` interpolated it at
+    Ok(poly.evaluate(password))
 }
 
 #[cfg(test)]
@@ -58,9 +85,9 @@ mod tests {
         let threshold = 4;
         let num_of_shares = 10;
 
-        let shares = shares(secret, password, num_of_shares, threshold);
+        let shares = shares(secret, password, num_of_shares, threshold).unwrap();
 
-        let recovered_secret = recover_secret(shares, password);
+        let recovered_secret = recover_secret(shares, password).unwrap();
 
         assert_eq!(recovered_secret, secret);
     }
@@ -72,9 +99,9 @@ mod tests {
         let threshold = 4;
         let num_of_shares = 10;
 
-        let shares = shares(secret, password, num_of_shares, threshold);
+        let shares = shares(secret, password, num_of_shares, threshold).unwrap();
 
-        let recovered_secret = recover_secret(shares, password);
+        let recovered_secret = recover_secret(shares, password).unwrap();
 
         assert_ne!(recovered_secret, Fq::from(10));
     }
@@ -86,10 +113,125 @@ mod tests {
         let threshold = 4;
         let num_of_shares = 10;
 
-        let shares = shares(secret, password, num_of_shares, threshold);
+        let shares = shares(secret, password, num_of_shares, threshold).unwrap();
+
+        let recovered_secret = recover_secret(shares, Fq::ZERO).unwrap();
+
+        assert_ne!(recovered_secret, secret);
+    }
+
+    #[test]
+    fn test_recover_with_password_colliding_with_share_index_fails() {
+        let secret = Fq::from(220284);
+        let password = Fq::from(123);
+        let threshold = 4;
+        let num_of_shares = 10;
+
+        let shares = shares(secret, password, num_of_shares, threshold).unwrap();
+
+        assert_eq!(recover_secret(shares.clone(), password), Ok(secret));
 
-        let recovered_secret = recover_secret(shares, Fq::ZERO);
+        // evaluating at a share's x-coordinate just hands back that share's y-coordinate
+        let wrong_password = Fq::from(3);
+        let recovered_secret = recover_secret(shares.clone(), wrong_password).unwrap();
 
         assert_ne!(recovered_secret, secret);
+        assert_eq!(recovered_secret, shares[2].1);
+    }
+
+    #[test]
+    fn test_shares_rejects_password_colliding_with_share_index() {
+        let secret = Fq::from(1729);
+        let password = Fq::from(3);
+        let threshold = 4;
+        let num_of_shares = 10;
+
+        assert_eq!(
+            shares(secret, password, num_of_shares, threshold),
+            Err(SssError::PasswordCollision)
+        );
+    }
+
+    #[test]
+    fn test_shares_hands_out_num_shares() {
+        let secret = Fq::from(1729);
+        let password = Fq::from(123);
+        let share_set = shares(secret, password, 5, 5).unwrap();
+
+        assert_eq!(
+            share_set.iter().map(|(x, _)| *x).collect::<Vec<_>>(),
+            (1..=5).map(Fq::from).collect::<Vec<_>>()
+        );
+        assert_eq!(recover_secret(share_set, password).unwrap(), secret);
+
+        // x = num_shares is handed out too, so it can't be the password
+        assert_eq!(
+            shares(secret, Fq::from(5), 5, 3),
+            Err(SssError::PasswordCollision)
+        );
+    }
+
+    #[test]
+    fn test_shares_with_password_invalid_threshold_errors() {
+        let password = Fq::from(123);
+
+        assert!(matches!(
+            shares(Fq::from(1729), password, 10, 0),
+            Err(SssError::InvalidThreshold { .. })
+        ));
+        assert!(matches!(
+            shares(Fq::from(1729), password, 3, 4),
+            Err(SssError::InvalidThreshold { .. })
+        ));
+    }
+
+    #[test]
+    fn test_recover_with_password_from_empty_shares_errors() {
+        assert_eq!(
+            recover_secret::<Fq>(vec![], Fq::from(123)),
+            Err(SssError::EmptyShares)
+        );
+    }
+
+    #[test]
+    fn test_shares_from_seeded_rng_are_reproducible() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let secret = Fq::from(1729);
+        let password = Fq::from(123);
+        let first =
+            shares_from_rng(secret, password, 10, 4, &mut StdRng::seed_from_u64(7)).unwrap();
+        let second =
+            shares_from_rng(secret, password, 10, 4, &mut StdRng::seed_from_u64(7)).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(recover_secret(first, password).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_shares_polynomial_has_full_degree() {
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let secret = Fq::from(1729);
+        let password = Fq::from(123);
+
+        for threshold in 1..=6 {
+            let mut rng = StdRng::seed_from_u64(threshold);
+            let shares = shares_from_rng(secret, password, 10, threshold, &mut rng).unwrap();
+            let threshold = threshold as usize;
+
+            // threshold shares plus the secret at the password pin down the whole polynomial
+            let (mut xs, mut ys): (Vec<Fq>, Vec<Fq>) = shares[..threshold].iter().copied().unzip();
+            xs.push(password);
+            ys.push(secret);
+
+            let poly = DenseUnivariatePolynomial::interpolate(&xs, &ys);
+
+            assert_eq!(poly.degree(), threshold - 1);
+            assert_eq!(
+                recover_secret(shares[..threshold].to_vec(), password).unwrap(),
+                secret
+            );
+        }
     }
 }
@@ -5,7 +5,9 @@ use polynomials::{
     composed::{ProductPolynomial, SumPolynomial},
     multilinear::MultilinearPolynomial,
 };
-use sumcheck::{prove, verify};
+use sha3::Keccak256;
+use sumcheck::{prove, sumcheck_over_multilinear, verify};
+use transcript::Transcript;
 
 // Generate a synthetic SumPolynomial for testing
 fn setup_polynomial(num_evals: usize) -> SumPolynomial<Fq> {
@@ -37,7 +39,7 @@ pub fn sumcheck_benchmarks(c: &mut Criterion) {
     let mut group = c.benchmark_group("sumcheck");
 
     let sum_polynomial = setup_polynomial(16);
-    let (claimed_sum, round_polys) = prove(sum_polynomial.clone());
+    let (claimed_sum, round_polys, _) = prove(sum_polynomial.clone());
 
     group.bench_function("sumcheck prove", |b| {
         b.iter(|| black_box(prove(sum_polynomial.clone())))
@@ -53,3 +55,34 @@ pub fn sumcheck_benchmarks(c: &mut Criterion) {
         })
     });
 }
+
+pub fn multilinear_absorption_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multilinear sumcheck absorption");
+    let mut rng = rand::thread_rng();
+    let num_vars = 16;
+    let evals: Vec<Fq> = (0..1 << num_vars).map(|_| Fq::rand(&mut rng)).collect();
+    let claimed_sum = evals.iter().sum();
+    let polynomial = MultilinearPolynomial::new(evals);
+
+    group.bench_function("absorb raw bytes", |b| {
+        b.iter(|| {
+            let mut transcript: Transcript<Fq, Keccak256> = Transcript::new();
+            transcript.append(&polynomial.to_bytes());
+            black_box(transcript.sample_field_element())
+        })
+    });
+
+    group.bench_function("absorb commitment digest", |b| {
+        b.iter(|| {
+            let mut transcript: Transcript<Fq, Keccak256> = Transcript::new();
+            transcript.append(&polynomial.commitment_digest::<Keccak256>());
+            black_box(transcript.sample_field_element())
+        })
+    });
+
+    group.bench_function("multilinear sumcheck prove", |b| {
+        b.iter(|| black_box(sumcheck_over_multilinear::prove(&polynomial, claimed_sum)))
+    });
+
+    group.finish();
+}
@@ -0,0 +1,112 @@
+use crate::prover::{SumcheckProverState, prove_with};
+use crate::verifier::{SumcheckError, partial_verify_detailed};
+use ark_ff::PrimeField;
+use polynomials::{Evaluable, univariate::DenseUnivariatePolynomial};
+use sha3::Keccak256;
+use transcript::Transcript;
+
+// one sumcheck for every `Evaluable` polynomial. it runs the same round loop and transcript
+// encoding as `prover::prove`, so round polynomials are interpolated from max_degree() + 1
+// evaluations: a multilinear sends lines and a sum of products sends polynomials of the
+// product degree.
+pub fn prove<F: PrimeField, P: Evaluable<F> + Clone>(
+    polynomial: &P,
+) -> (F, Vec<DenseUnivariatePolynomial<F>>, Vec<F>) {
+    prove_with(SumcheckProverState::new(polynomial.clone()), false)
+        .expect("Rounds are only checked on request")
+}
+
+pub fn verify_detailed<F: PrimeField, P: Evaluable<F>>(
+    polynomial: &P,
+    claimed_sum: F,
+    round_polynomials: Vec<DenseUnivariatePolynomial<F>>,
+) -> Result<(), SumcheckError> {
+    if round_polynomials.len() != polynomial.n_vars() {
+        return Err(SumcheckError::RoundCountMismatch {
+            expected: polynomial.n_vars(),
+            actual: round_polynomials.len(),
+        });
+    }
+
+    let mut transcript: Transcript<F, Keccak256> = Transcript::new();
+
+    polynomial.absorb_into(&mut transcript);
+
+    let (final_claim, challenges) = partial_verify_detailed(
+        &mut transcript,
+        claimed_sum,
+        round_polynomials,
+        polynomial.max_degree(),
+    )?;
+
+    if polynomial.evaluate(&challenges) != final_claim {
+        return Err(SumcheckError::OracleCheckFailed);
+    }
+
+    Ok(())
+}
+
+pub fn verify<F: PrimeField, P: Evaluable<F>>(
+    polynomial: &P,
+    claimed_sum: F,
+    round_polynomials: Vec<DenseUnivariatePolynomial<F>>,
+) -> bool {
+    verify_detailed(polynomial, claimed_sum, round_polynomials).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
+    use polynomials::{
+        composed::{ProductPolynomial, SumPolynomial},
+        multilinear::MultilinearPolynomial,
+    };
+
+    fn random_poly(n_vars: usize) -> MultilinearPolynomial<Fq> {
+        let mut rng = rand::thread_rng();
+
+        MultilinearPolynomial::new((0..1 << n_vars).map(|_| Fq::rand(&mut rng)).collect())
+    }
+
+    #[test]
+    fn test_generic_sumcheck_over_multilinear() {
+        let polynomial = random_poly(4);
+        let (claimed_sum, round_polys, challenges) = prove(&polynomial);
+
+        assert_eq!(claimed_sum, polynomial.evals_slice().iter().sum::<Fq>());
+        assert_eq!(challenges.len(), 4);
+        assert!(round_polys.iter().all(|poly| poly.degree() <= 1));
+        assert!(verify(&polynomial, claimed_sum, round_polys.clone()));
+        assert_eq!(
+            verify_detailed(&polynomial, claimed_sum + Fq::from(1u64), round_polys),
+            Err(SumcheckError::SumRelationFailed { round: 0 })
+        );
+    }
+
+    #[test]
+    fn test_generic_sumcheck_over_sum_polynomial() {
+        let polynomial = SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![random_poly(3), random_poly(3)]),
+            ProductPolynomial::new(vec![random_poly(3), random_poly(3), random_poly(3)]),
+        ]);
+        let (claimed_sum, round_polys, _) = prove(&polynomial);
+
+        assert_eq!(claimed_sum, polynomial.reduce().iter().sum::<Fq>());
+        assert!(round_polys.iter().all(|poly| poly.degree() <= 3));
+        assert!(verify(&polynomial, claimed_sum, round_polys.clone()));
+        assert!(!verify(&random_poly(3), claimed_sum, round_polys));
+    }
+
+    #[test]
+    fn test_generic_sumcheck_matches_composed_prover() {
+        let polynomial = SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![random_poly(3), random_poly(3)]),
+            ProductPolynomial::new(vec![random_poly(3), random_poly(3)]),
+        ]);
+
+        // one round loop and one transcript encoding, so the proofs are identical
+        assert_eq!(prove(&polynomial), crate::prover::prove(polynomial.clone()));
+    }
+}
@@ -1,7 +1,8 @@
+use crate::prover::{SumcheckProverState, prove_rounds};
 use ark_ff::PrimeField;
-use sha3::Keccak256;
 use polynomials::composed::SumPolynomial;
 use polynomials::univariate::DenseUnivariatePolynomial;
+use sha3::Keccak256;
 use transcript::Transcript;
 
 #[derive(Debug)]
@@ -11,35 +12,41 @@ pub struct SumcheckProof<F: PrimeField> {
     challenges: Vec<F>,
 }
 
+impl<F: PrimeField> SumcheckProof<F> {
+    pub fn claimed_sum(&self) -> F {
+        self.claimed_sum
+    }
+
+    pub fn round_polynomials(&self) -> &[DenseUnivariatePolynomial<F>] {
+        &self.round_polynomials
+    }
+
+    pub fn challenges(&self) -> &[F] {
+        &self.challenges
+    }
+}
+
 pub fn prove<F: PrimeField>(claimed_sum: F, sum_polynomial: SumPolynomial<F>) -> SumcheckProof<F> {
     let mut transcript: Transcript<F, Keccak256> = Transcript::new();
 
     transcript.append_field_element(&claimed_sum);
-    transcript.append(&sum_polynomial.to_bytes());
+    sum_polynomial.absorb_into(&mut transcript);
 
     prover_partial(claimed_sum, sum_polynomial, &mut transcript)
 }
 
-pub fn prover_partial<F: PrimeField>(claimed_sum: F, sum_polynomial: SumPolynomial<F>, transcript: &mut Transcript<F, Keccak256>) -> SumcheckProof<F> {
-    transcript.append_field_element(&claimed_sum);
-
-    let mut round_polynomials = vec![];
-    let mut challenges = vec![];
-    let mut current_polynomial = sum_polynomial.clone();
-
-    for i in 0..sum_polynomial.n_vars() {
-        let round_polynomial_evals = get_round_polynomial(sum_polynomial.clone());
-        let xs = (0..sum_polynomial.degree() + 1).map(|x| F::from(x as u64)).collect::<Vec<F>>();
-        let univariate_polynomial = DenseUnivariatePolynomial::interpolate(&xs, &round_polynomial_evals);
-
-        transcript.append(&univariate_polynomial.to_bytes());
-        round_polynomials.push(univariate_polynomial);
-
-        let challenge = transcript.sample_field_element();
-
-        current_polynomial = current_polynomial.partial_evaluate(challenge, 0);
-        challenges.push(challenge);
-    }
+pub fn prover_partial<F: PrimeField>(
+    claimed_sum: F,
+    sum_polynomial: SumPolynomial<F>,
+    transcript: &mut Transcript<F, Keccak256>,
+) -> SumcheckProof<F> {
+    let (round_polynomials, challenges) = prove_rounds(
+        claimed_sum,
+        SumcheckProverState::new(sum_polynomial),
+        transcript,
+        false,
+    )
+    .expect("Rounds are only checked on request");
 
     SumcheckProof {
         claimed_sum,
@@ -48,18 +55,38 @@ pub fn prover_partial<F: PrimeField>(claimed_sum: F, sum_polynomial: SumPolynomi
     }
 }
 
-fn get_round_polynomial<F: PrimeField>(polynomial: SumPolynomial<F>) -> Vec<F> {
-    let num_evals = polynomial.degree() + 1;
-    let mut evals = Vec::with_capacity(num_evals);
-
-    for i in 0..num_evals {
-        let point = F::from(i as u64);
-        let partial_polynomial = polynomial.partial_evaluate(point, 0);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use polynomials::{composed::ProductPolynomial, multilinear::MultilinearPolynomial};
 
-        let eval = partial_polynomial.element_wise_add().evals_slice().iter().sum();
-
-        evals.push(eval);
+    fn fq(x: u64) -> Fq {
+        Fq::from(x)
     }
 
-    evals
+    #[test]
+    fn test_proof_accessors() {
+        let product_poly1 = ProductPolynomial::new(vec![
+            MultilinearPolynomial::new(vec![fq(0), fq(0), fq(0), fq(2)]),
+            MultilinearPolynomial::new(vec![fq(0), fq(0), fq(0), fq(3)]),
+        ]);
+        let product_poly2 = ProductPolynomial::new(vec![
+            MultilinearPolynomial::new(vec![fq(0), fq(0), fq(0), fq(2)]),
+            MultilinearPolynomial::new(vec![fq(0), fq(0), fq(0), fq(3)]),
+        ]);
+        let sum_polynomial = SumPolynomial::new(vec![product_poly1, product_poly2]);
+
+        let proof = prove(fq(12), sum_polynomial.clone());
+
+        assert_eq!(proof.claimed_sum(), fq(12));
+        assert_eq!(proof.round_polynomials().len(), sum_polynomial.n_vars());
+        assert_eq!(proof.challenges().len(), sum_polynomial.n_vars());
+
+        let first_round = &proof.round_polynomials()[0];
+        assert_eq!(
+            first_round.evaluate(fq(0)) + first_round.evaluate(fq(1)),
+            proof.claimed_sum()
+        );
+    }
 }
@@ -1,4 +1,8 @@
+pub mod generic;
+pub mod gkr_sumcheck;
+pub mod product;
 pub mod prover;
+pub mod sumcheck_over_multilinear;
 pub mod verifier;
 
 pub use prover::*;
@@ -8,13 +12,18 @@ pub use verifier::*;
 mod tests {
     use super::*;
     use ark_bls12_381::Fq;
+    use ark_serialize::CanonicalSerialize;
+    use polynomials::util::signed;
     use polynomials::{
         composed::{ProductPolynomial, SumPolynomial},
         multilinear::MultilinearPolynomial,
+        univariate::DenseUnivariatePolynomial,
     };
+    use sha3::Keccak256;
+    use transcript::Transcript;
 
     fn fq(x: i64) -> Fq {
-        Fq::from(x)
+        signed(x)
     }
 
     fn poly1a() -> MultilinearPolynomial<Fq> {
@@ -121,6 +130,38 @@ mod tests {
         assert!(verify(sum_poly(), claimed_sum, round_polys))
     }
 
+    #[test]
+    fn test_proof_is_bound_to_absorbed_statement() {
+        let (claimed_sum, round_polys, challenges) = prove(sum_poly());
+
+        // the verifier re-derives the prover's challenges from its own absorbed statement
+        let mut verifier_transcript = Transcript::<Fq, Keccak256>::new();
+        sum_poly().absorb_into(&mut verifier_transcript);
+        let (_, verifier_challenges) = partial_verify_detailed(
+            &mut verifier_transcript,
+            claimed_sum,
+            round_polys.clone(),
+            sum_poly().degree(),
+        )
+        .unwrap();
+
+        assert_eq!(verifier_challenges, challenges);
+        assert_eq!(
+            verify_detailed(sum_poly(), claimed_sum, round_polys.clone()),
+            Ok(())
+        );
+
+        // same polynomial with the products swapped: it evaluates identically everywhere, so
+        // round 0 still checks out and only the challenge drawn from the absorbed encoding
+        // breaks the next round
+        let reordered = SumPolynomial::new(vec![prod_poly2(), prod_poly1()]);
+
+        assert_eq!(
+            verify_detailed(reordered, claimed_sum, round_polys),
+            Err(SumcheckError::SumRelationFailed { round: 1 })
+        );
+    }
+
     // This test is from Sir Casweeney: https://github.com/casweeney/zk-cryptography-research-implementations/blob/main/sumcheck_protocol/src/gkr_sumcheck/sumcheck_gkr_protocol.rs
     #[test]
     fn test_prover_and_verifier() {
@@ -140,4 +181,330 @@ mod tests {
 
         assert_eq!(verified, true);
     }
+
+    #[test]
+    fn test_verify_ct() {
+        let (claimed_sum, round_polys, _) = prove(sum_poly());
+
+        assert!(verify_ct(sum_poly(), claimed_sum, round_polys.clone()));
+        assert!(!verify_ct(sum_poly(), claimed_sum + fq(1), round_polys));
+    }
+
+    #[test]
+    fn test_verify_rejects_truncated_round_polynomials() {
+        let (claimed_sum, mut round_polys, _) = prove(sum_poly());
+
+        round_polys.pop();
+
+        assert!(!verify(sum_poly(), claimed_sum, round_polys));
+    }
+
+    #[test]
+    fn test_verify_detailed_round_count_mismatch() {
+        let (claimed_sum, mut round_polys, _) = prove(sum_poly());
+
+        round_polys.pop();
+
+        assert_eq!(
+            verify_detailed(sum_poly(), claimed_sum, round_polys),
+            Err(SumcheckError::RoundCountMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_verify_detailed_tampered_claimed_sum() {
+        let (claimed_sum, round_polys, _) = prove(sum_poly());
+
+        assert_eq!(
+            verify_detailed(sum_poly(), claimed_sum + fq(1), round_polys),
+            Err(SumcheckError::SumRelationFailed { round: 0 })
+        );
+    }
+
+    #[test]
+    fn test_verify_detailed_tampered_oracle_value() {
+        let (claimed_sum, mut round_polys, _) = prove(sum_poly());
+
+        // adding x^2 - x leaves p(0) + p(1) untouched but moves p(r) for any other r
+        let last_round = round_polys.pop().unwrap();
+        let tamper = DenseUnivariatePolynomial::new(vec![fq(0), fq(-1), fq(1)]);
+        round_polys.push(&last_round + &tamper);
+
+        assert_eq!(
+            verify_detailed(sum_poly(), claimed_sum, round_polys),
+            Err(SumcheckError::OracleCheckFailed)
+        );
+    }
+
+    #[test]
+    fn test_verify_detailed_rejects_over_degree_round_polynomial() {
+        let (claimed_sum, mut round_polys, _) = prove(sum_poly());
+        let max_degree = sum_poly().degree();
+
+        // x^(d+1) - x keeps p(0) + p(1) intact but pushes the first round past the degree bound
+        let mut coefficients = vec![fq(0); max_degree + 2];
+        coefficients[1] = fq(-1);
+        coefficients[max_degree + 1] = fq(1);
+        let tamper = DenseUnivariatePolynomial::new(coefficients);
+        round_polys[0] = &round_polys[0] + &tamper;
+
+        assert_eq!(
+            verify_detailed(sum_poly(), claimed_sum, round_polys.clone()),
+            Err(SumcheckError::DegreeBoundExceeded {
+                round: 0,
+                degree: max_degree + 1,
+                max_degree
+            })
+        );
+
+        let compressed_round_polys = round_polys
+            .iter()
+            .map(|round_poly| compress_round_polynomial(round_poly, max_degree + 1))
+            .collect();
+
+        assert_eq!(
+            verify_compressed_detailed(sum_poly(), claimed_sum, compressed_round_polys),
+            Err(SumcheckError::CompressedRoundLengthMismatch {
+                round: 0,
+                expected: max_degree,
+                actual: max_degree + 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_evaluate_rounds() {
+        let (_, round_polys, challenges) = prove(sum_poly());
+        let points = [fq(0), fq(1), challenges[0]];
+        let evals = evaluate_rounds(&round_polys, &points);
+
+        assert_eq!(evals.len(), round_polys.len());
+
+        for (round_poly, round_evals) in round_polys.iter().zip(&evals) {
+            let expected: Vec<Fq> = points.iter().map(|&x| round_poly.evaluate(x)).collect();
+
+            assert_eq!(round_evals, &expected);
+        }
+
+        assert!(evaluate_rounds(&round_polys, &[]).iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn test_compressed_proof() {
+        let (claimed_sum, round_polys, challenges) = prove(sum_poly());
+        let (compressed_claimed_sum, compressed_round_polys, compressed_challenges) =
+            prove_compressed(sum_poly());
+
+        assert_eq!(claimed_sum, compressed_claimed_sum);
+        assert_eq!(challenges, compressed_challenges);
+
+        // every round sends exactly max_degree evaluations, p(0) is left out
+        assert!(
+            compressed_round_polys
+                .iter()
+                .all(|compressed| compressed.len() == sum_poly().degree())
+        );
+
+        assert!(verify(sum_poly(), claimed_sum, round_polys));
+        assert!(verify_compressed(
+            sum_poly(),
+            claimed_sum,
+            compressed_round_polys.clone()
+        ));
+        assert_eq!(
+            verify_compressed_detailed(sum_poly(), claimed_sum + fq(1), compressed_round_polys),
+            Err(SumcheckError::OracleCheckFailed)
+        );
+    }
+
+    #[test]
+    fn test_verify_compressed_rejects_short_rounds() {
+        let (claimed_sum, compressed_round_polys, _) = prove_compressed(sum_poly());
+
+        for length in [0, 1] {
+            let mut short = compressed_round_polys.clone();
+            short[1].truncate(length);
+
+            assert_eq!(
+                verify_compressed_detailed(sum_poly(), claimed_sum, short),
+                Err(SumcheckError::CompressedRoundLengthMismatch {
+                    round: 1,
+                    expected: 2,
+                    actual: length
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_compressed_proof_with_round_constant_in_its_variable() {
+        // nothing depends on x0, so the first round polynomial is the constant claim / 2
+        let sum_polynomial = SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![
+                MultilinearPolynomial::new(vec![fq(1), fq(2), fq(1), fq(2)]),
+                MultilinearPolynomial::new(vec![fq(3), fq(5), fq(3), fq(5)]),
+            ]),
+            ProductPolynomial::new(vec![
+                MultilinearPolynomial::new(vec![fq(1); 4]),
+                MultilinearPolynomial::new(vec![fq(1); 4]),
+            ]),
+        ]);
+        let (claimed_sum, round_polys, _) = prove(sum_polynomial.clone());
+
+        assert_eq!(round_polys[0].degree(), 0);
+        assert!(verify(sum_polynomial.clone(), claimed_sum, round_polys));
+
+        let (claimed_sum, compressed_round_polys, _) = prove_compressed(sum_polynomial.clone());
+
+        assert_eq!(compressed_round_polys[0].len(), 2);
+        assert!(verify_compressed(
+            sum_polynomial,
+            claimed_sum,
+            compressed_round_polys
+        ));
+    }
+
+    #[test]
+    fn test_partial_verify_rejects_empty_proof() {
+        let mut transcript = Transcript::<Fq, Keccak256>::new();
+
+        assert_eq!(
+            partial_verify_detailed(&mut transcript, fq(12), vec![], 2),
+            Err(SumcheckError::EmptyProof)
+        );
+        assert_eq!(
+            partial_verify_compressed(&mut transcript, fq(12), vec![], 2),
+            Err(SumcheckError::EmptyProof)
+        );
+    }
+
+    #[test]
+    fn test_partial_sumcheck_with_blake3() {
+        let mut prover_transcript = Transcript::<Fq, blake3::Hasher>::new();
+        let (claimed_sum, _, _) = prove(sum_poly());
+        let (round_polys, prover_challenges) =
+            partial_prove(claimed_sum, sum_poly(), &mut prover_transcript);
+
+        let mut verifier_transcript = Transcript::<Fq, blake3::Hasher>::new();
+        let (final_claim, challenges) = partial_verify_detailed(
+            &mut verifier_transcript,
+            claimed_sum,
+            round_polys,
+            sum_poly().degree(),
+        )
+        .unwrap();
+
+        assert_eq!(challenges, prover_challenges);
+        assert_eq!(sum_poly().evaluate(&challenges), final_claim);
+    }
+
+    #[test]
+    fn test_proof_bytes_round_trip() {
+        let bytes = prove_to_bytes(sum_poly());
+
+        assert!(verify_from_bytes(sum_poly(), &bytes));
+        assert!(!verify_from_bytes(sum_poly(), &bytes[..bytes.len() - 1]));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+
+        assert!(!verify_from_bytes(sum_poly(), &trailing));
+
+        let (claimed_sum, round_polys, _) = prove(sum_poly());
+        let mut tampered = Vec::new();
+        (claimed_sum + fq(1), round_polys)
+            .serialize_compressed(&mut tampered)
+            .unwrap();
+
+        assert!(!verify_from_bytes(sum_poly(), &tampered));
+    }
+
+    #[test]
+    fn test_chained_partial_sumchecks() {
+        let first = sum_poly();
+        let second = SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![poly1a(), poly1a()]),
+            ProductPolynomial::new(vec![poly1b(), poly1b()]),
+        ]);
+        let (first_sum, _, _) = prove(first.clone());
+        let (second_sum, _, _) = prove(second.clone());
+
+        let mut prover_transcript = Transcript::<Fq, Keccak256>::new();
+        let (first_round_polys, _) =
+            partial_prove(first_sum, first.clone(), &mut prover_transcript);
+        let (second_round_polys, second_prover_challenges) =
+            partial_prove(second_sum, second.clone(), &mut prover_transcript);
+
+        let mut verifier_transcript = Transcript::<Fq, Keccak256>::new();
+        let (first_ok, first_claim, first_challenges) = partial_verify(
+            &mut verifier_transcript,
+            first_sum,
+            first_round_polys,
+            first.degree(),
+        );
+        let (second_ok, second_claim, second_challenges) = partial_verify(
+            &mut verifier_transcript,
+            second_sum,
+            second_round_polys,
+            second.degree(),
+        );
+
+        assert!(first_ok && second_ok);
+        assert_eq!(first.evaluate(&first_challenges), first_claim);
+        assert_eq!(second.evaluate(&second_challenges), second_claim);
+
+        // the second sumcheck's challenges depend on everything absorbed by the first
+        assert_eq!(second_challenges, second_prover_challenges);
+        assert_ne!(prove(second).2, second_challenges);
+    }
+}
+
+// same provers as above but over field_tracker's Ft, so their field-op counts can be compared
+#[cfg(all(test, feature = "track"))]
+mod track_tests {
+    use super::*;
+    use ark_bls12_381::Fq as ArkField;
+    use ark_ff::UniformRand;
+    use field_tracker::{Ft, end_tscope, print_summary, start_tscope};
+    use polynomials::{
+        composed::{ProductPolynomial, SumPolynomial},
+        multilinear::MultilinearPolynomial,
+    };
+
+    type Fq = Ft!(ArkField);
+
+    fn random_poly(n_vars: usize) -> MultilinearPolynomial<Fq> {
+        let mut rng = rand::thread_rng();
+
+        MultilinearPolynomial::new((0..1 << n_vars).map(|_| Fq::rand(&mut rng)).collect())
+    }
+
+    fn random_sum_poly(n_vars: usize) -> SumPolynomial<Fq> {
+        SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![random_poly(n_vars), random_poly(n_vars)]),
+            ProductPolynomial::new(vec![random_poly(n_vars), random_poly(n_vars)]),
+        ])
+    }
+
+    #[test]
+    fn test_track_composed_sumcheck() {
+        let sum_polynomial = random_sum_poly(6);
+
+        start_tscope!("composed sumcheck prover");
+        let (claimed_sum, round_polys, _) = prove(sum_polynomial.clone());
+        end_tscope!();
+
+        start_tscope!("composed sumcheck verifier");
+        assert!(verify(sum_polynomial.clone(), claimed_sum, round_polys));
+        end_tscope!();
+
+        start_tscope!("gkr sumcheck prover");
+        gkr_sumcheck::prove(claimed_sum, sum_polynomial);
+        end_tscope!();
+
+        print_summary!();
+    }
 }
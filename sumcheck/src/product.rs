@@ -0,0 +1,70 @@
+use crate::{prover, verifier};
+use ark_ff::PrimeField;
+use polynomials::{
+    composed::{ProductPolynomial, SumPolynomial},
+    multilinear::MultilinearPolynomial,
+    univariate::DenseUnivariatePolynomial,
+};
+
+// the claim ∑_{x ∈ {0,1}^n} f(x)·g(x) = S, i.e. the product of two layer polynomials a GKR
+// round reduces to. it is the composed sumcheck over a single degree-2 product
+fn product_sum_polynomial<F: PrimeField>(
+    f: &MultilinearPolynomial<F>,
+    g: &MultilinearPolynomial<F>,
+) -> SumPolynomial<F> {
+    SumPolynomial::new(vec![ProductPolynomial::new(vec![f.clone(), g.clone()])])
+}
+
+pub fn prove<F: PrimeField>(
+    f: &MultilinearPolynomial<F>,
+    g: &MultilinearPolynomial<F>,
+) -> (F, Vec<DenseUnivariatePolynomial<F>>, Vec<F>) {
+    prover::prove(product_sum_polynomial(f, g))
+}
+
+pub fn verify<F: PrimeField>(
+    f: &MultilinearPolynomial<F>,
+    g: &MultilinearPolynomial<F>,
+    claimed_sum: F,
+    round_polynomials: Vec<DenseUnivariatePolynomial<F>>,
+) -> bool {
+    verifier::verify(product_sum_polynomial(f, g), claimed_sum, round_polynomials)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
+
+    fn random_poly(n_vars: usize) -> MultilinearPolynomial<Fq> {
+        let mut rng = rand::thread_rng();
+
+        MultilinearPolynomial::new((0..1 << n_vars).map(|_| Fq::rand(&mut rng)).collect())
+    }
+
+    #[test]
+    fn test_product_sumcheck() {
+        let f = random_poly(4);
+        let g = random_poly(4);
+        let inner_product: Fq = f
+            .evals_slice()
+            .iter()
+            .zip(g.evals_slice())
+            .map(|(a, b)| *a * b)
+            .sum();
+
+        let (claimed_sum, round_polys, challenges) = prove(&f, &g);
+
+        assert_eq!(claimed_sum, inner_product);
+        assert_eq!(challenges.len(), 4);
+        assert!(verify(&f, &g, claimed_sum, round_polys.clone()));
+        assert!(!verify(
+            &f,
+            &g,
+            claimed_sum + Fq::from(1u64),
+            round_polys.clone()
+        ));
+        assert!(!verify(&f, &random_poly(4), claimed_sum, round_polys));
+    }
+}
@@ -1,60 +1,338 @@
 use ark_ff::PrimeField;
-use polynomials::{composed::SumPolynomial, univariate::DenseUnivariatePolynomial};
-use sha3::Keccak256;
+use ark_serialize::CanonicalSerialize;
+use polynomials::{Evaluable, composed::SumPolynomial, univariate::DenseUnivariatePolynomial};
+use sha3::{Digest, Keccak256, digest::FixedOutputReset};
+use std::fmt;
+use std::marker::PhantomData;
 use transcript::Transcript;
 
-pub fn partial_prove<F: PrimeField>(
-    mut sum_polynomial: SumPolynomial<F>,
-    transcript: &mut Transcript<F, Keccak256>,
-) -> (F, Vec<DenseUnivariatePolynomial<F>>, Vec<F>) {
-    let claimed_sum = sum_polynomial
-        .element_wise_add()
-        .evals_slice()
-        .into_iter()
-        .sum();
-    let n_vars = sum_polynomial.n_vars();
-    let mut round_polynomials = Vec::with_capacity(n_vars);
-    let mut challenges = Vec::with_capacity(n_vars);
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProverError {
+    RoundSumMismatch { round: usize },
+}
 
-    transcript.append_field_element(&claimed_sum);
+impl fmt::Display for ProverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProverError::RoundSumMismatch { round } => write!(
+                f,
+                "round {} polynomial does not sum to the running claim over {{0, 1}}",
+                round
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProverError {}
+
+// (claimed_sum, round_polynomials, challenges), as returned by `prove`
+type Proof<F> = (F, Vec<DenseUnivariatePolynomial<F>>, Vec<F>);
+
+// sumcheck over a shared transcript for composing protocols like GKR, where the claim comes
+// from the previous reduction instead of being summed up here. the claim is absorbed before
+// the first round, and a wrong claim gives a proof that fails `partial_verify`. returns the
+// round polynomials and the challenges, the reduced claim is the last round polynomial
+// evaluated at the last challenge.
+pub fn partial_prove<F: PrimeField, H: Clone + Digest + FixedOutputReset>(
+    claimed_sum: F,
+    sum_polynomial: SumPolynomial<F>,
+    transcript: &mut Transcript<F, H>,
+) -> (Vec<DenseUnivariatePolynomial<F>>, Vec<F>) {
+    prove_rounds(
+        claimed_sum,
+        SumcheckProverState::new(sum_polynomial),
+        transcript,
+        false,
+    )
+    .expect("Rounds are only checked on request")
+}
+
+// the prover one round at a time, for when the challenges come from somewhere other than a
+// local transcript or the proof shouldn't be built up in memory. round i is fed the
+// challenge of round i - 1, which fixes the leading variable before the next round
+// polynomial is computed. this is the one round loop behind every sumcheck prover here, for
+// a `SumPolynomial` by default and for any `Evaluable` through `generic::prove`.
+#[derive(Debug, Clone)]
+pub struct SumcheckProverState<F: PrimeField, P: Evaluable<F> = SumPolynomial<F>> {
+    polynomial: P,
+    round: usize,
+    num_rounds: usize,
+    _field: PhantomData<F>,
+}
 
-    for _ in 0..n_vars {
-        let num_evals = sum_polynomial.degree() + 1;
-        let mut evals = Vec::with_capacity(num_evals);
+impl<F: PrimeField, P: Evaluable<F>> SumcheckProverState<F, P> {
+    pub fn new(polynomial: P) -> Self {
+        Self {
+            num_rounds: polynomial.n_vars(),
+            polynomial,
+            round: 0,
+            _field: PhantomData,
+        }
+    }
 
-        for i in 0..num_evals {
-            let point = F::from(i as u64);
-            let partial_polynomial = sum_polynomial.partial_evaluate(point, 0);
+    pub fn num_rounds(&self) -> usize {
+        self.num_rounds
+    }
 
-            let eval: F = partial_polynomial
-                .element_wise_add()
-                .evals_slice()
-                .iter()
-                .sum();
+    pub fn next_round(&mut self, challenge: Option<F>) -> DenseUnivariatePolynomial<F> {
+        assert!(
+            self.round < self.num_rounds,
+            "All {} rounds have already been run",
+            self.num_rounds
+        );
 
-            evals.push(eval);
+        match challenge {
+            None => assert!(
+                self.round == 0,
+                "Round {} needs the challenge of the previous round",
+                self.round
+            ),
+            Some(challenge) => {
+                assert!(self.round > 0, "The first round takes no challenge");
+
+                self.polynomial = self.polynomial.partial_evaluate(challenge, 0);
+            }
         }
 
-        let round_polynomial = DenseUnivariatePolynomial::interpolate_y(evals);
+        self.round += 1;
 
-        transcript.append(&round_polynomial.to_bytes());
-        round_polynomials.push(round_polynomial);
+        // max_degree() + 1 evaluations pin down the round polynomial
+        let evals: Vec<F> = (0..=self.polynomial.max_degree())
+            .map(|x| {
+                self.polynomial
+                    .partial_evaluate(F::from(x as u64), 0)
+                    .sum_over_hypercube()
+            })
+            .collect();
 
-        let challenge = transcript.sample_field_element();
-        challenges.push(challenge);
+        DenseUnivariatePolynomial::interpolate_y(&evals)
+    }
+}
 
-        sum_polynomial = sum_polynomial.partial_evaluate(challenge, 0);
+// runs the rounds with challenges from the transcript. with `check` set, every round
+// polynomial is held to p(0) + p(1) = running claim before it is sent, so a prover bug
+// surfaces as the round it happened in instead of as a rejected proof.
+pub(crate) fn prove_rounds<F: PrimeField, P: Evaluable<F>, H: Clone + Digest + FixedOutputReset>(
+    claimed_sum: F,
+    mut state: SumcheckProverState<F, P>,
+    transcript: &mut Transcript<F, H>,
+    check: bool,
+) -> Result<(Vec<DenseUnivariatePolynomial<F>>, Vec<F>), ProverError> {
+    let n_vars = state.num_rounds();
+    let mut round_polynomials = Vec::with_capacity(n_vars);
+    let mut challenges: Vec<F> = Vec::with_capacity(n_vars);
+    let mut claim = claimed_sum;
+
+    transcript.append_field_element(&claimed_sum);
+
+    for round in 0..n_vars {
+        let round_polynomial = state.next_round(challenges.last().copied());
+
+        if check && round_polynomial.evaluate(F::ZERO) + round_polynomial.evaluate(F::ONE) != claim
+        {
+            return Err(ProverError::RoundSumMismatch { round });
+        }
+
+        let challenge = transcript.challenge_after(&round_polynomial.to_bytes());
+        claim = round_polynomial.evaluate(challenge);
+        round_polynomials.push(round_polynomial);
+        challenges.push(challenge);
     }
 
-    (claimed_sum, round_polynomials, challenges)
+    Ok((round_polynomials, challenges))
 }
 
 pub fn prove<F: PrimeField>(
     sum_polynomial: SumPolynomial<F>,
 ) -> (F, Vec<DenseUnivariatePolynomial<F>>, Vec<F>) {
+    prove_with(SumcheckProverState::new(sum_polynomial), false)
+        .expect("Rounds are only checked on request")
+}
+
+// `prove` with every round checked against the running claim before it is sent
+pub fn prove_checked<F: PrimeField>(
+    sum_polynomial: SumPolynomial<F>,
+) -> Result<Proof<F>, ProverError> {
+    prove_with(SumcheckProverState::new(sum_polynomial), true)
+}
+
+// a standalone proof: the polynomial is absorbed into a fresh transcript and the claim is its
+// sum over the hypercube
+pub(crate) fn prove_with<F: PrimeField, P: Evaluable<F>>(
+    state: SumcheckProverState<F, P>,
+    check: bool,
+) -> Result<Proof<F>, ProverError> {
+    let claimed_sum = state.polynomial.sum_over_hypercube();
     let mut transcript: Transcript<F, Keccak256> = Transcript::new();
 
-    transcript.append(&sum_polynomial.to_bytes());
+    state.polynomial.absorb_into(&mut transcript);
+
+    let (round_polynomials, challenges) = prove_rounds(claimed_sum, state, &mut transcript, check)?;
+
+    Ok((claimed_sum, round_polynomials, challenges))
+}
 
-    partial_prove(sum_polynomial, &mut transcript)
+// the proof as (claimed_sum, round_polynomials) in compressed canonical encoding. the
+// challenges are left out since the verifier re-derives them from the transcript.
+pub fn prove_to_bytes<F: PrimeField>(sum_polynomial: SumPolynomial<F>) -> Vec<u8> {
+    let (claimed_sum, round_polynomials, _) = prove(sum_polynomial);
+    let mut bytes = Vec::new();
+
+    (claimed_sum, round_polynomials)
+        .serialize_compressed(&mut bytes)
+        .expect("Serializing into a Vec can't fail");
+
+    bytes
+}
+
+// a round polynomial sent as its evaluations at 1..=max_degree. p(0) is left out since the
+// verifier recovers it from the sum relation p(0) + p(1) = claim. the count comes from the
+// degree bound, not the coefficients, since a round that doesn't depend on its variable is
+// normalized down to a constant but still needs all max_degree evaluations
+pub fn compress_round_polynomial<F: PrimeField>(
+    round_polynomial: &DenseUnivariatePolynomial<F>,
+    max_degree: usize,
+) -> Vec<F> {
+    (1..=max_degree)
+        .map(|i| round_polynomial.evaluate(F::from(i as u64)))
+        .collect()
+}
+
+pub fn prove_compressed<F: PrimeField>(
+    sum_polynomial: SumPolynomial<F>,
+) -> (F, Vec<Vec<F>>, Vec<F>) {
+    let max_degree = sum_polynomial.degree();
+    let (claimed_sum, round_polynomials, challenges) = prove(sum_polynomial);
+
+    let compressed_round_polynomials = round_polynomials
+        .iter()
+        .map(|round_polynomial| compress_round_polynomial(round_polynomial, max_degree))
+        .collect();
+
+    (claimed_sum, compressed_round_polynomials, challenges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
+    use polynomials::{composed::ProductPolynomial, multilinear::MultilinearPolynomial};
+
+    fn random_sum_poly(n_vars: usize) -> SumPolynomial<Fq> {
+        let mut rng = rand::thread_rng();
+        let mut random_poly =
+            || MultilinearPolynomial::new((0..1 << n_vars).map(|_| Fq::rand(&mut rng)).collect());
+
+        SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![random_poly(), random_poly()]),
+            ProductPolynomial::new(vec![random_poly(), random_poly()]),
+        ])
+    }
+
+    #[test]
+    fn test_prove_checked_matches_prove() {
+        let polynomial = random_sum_poly(3);
+
+        assert_eq!(prove_checked(polynomial.clone()), Ok(prove(polynomial)));
+    }
+
+    #[test]
+    fn test_prover_state_matches_prove() {
+        let polynomial = random_sum_poly(3);
+        let claimed_sum: Fq = polynomial.reduce().iter().sum();
+        let mut state = SumcheckProverState::new(polynomial.clone());
+        let mut transcript = Transcript::<Fq, Keccak256>::new();
+
+        polynomial.absorb_into(&mut transcript);
+        transcript.append_field_element(&claimed_sum);
+
+        let mut round_polynomials = vec![];
+        let mut challenges = vec![];
+        let mut challenge = None;
+
+        for _ in 0..state.num_rounds() {
+            let round_polynomial = state.next_round(challenge);
+            let next_challenge = transcript.challenge_after(&round_polynomial.to_bytes());
+
+            round_polynomials.push(round_polynomial);
+            challenges.push(next_challenge);
+            challenge = Some(next_challenge);
+        }
+
+        assert_eq!(
+            (claimed_sum, round_polynomials, challenges),
+            prove(polynomial)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Round 1 needs the challenge of the previous round")]
+    fn test_prover_state_missing_challenge() {
+        let mut state = SumcheckProverState::new(random_sum_poly(2));
+
+        state.next_round(None);
+        state.next_round(None);
+    }
+
+    // an `Evaluable` that goes wrong once a challenge is bound: every sum over the hypercube
+    // is off by one from then on, like a prover that folds the wrong variable
+    struct CorruptedAfterBinding {
+        polynomial: SumPolynomial<Fq>,
+        bindings: usize,
+    }
+
+    impl Evaluable<Fq> for CorruptedAfterBinding {
+        fn n_vars(&self) -> usize {
+            self.polynomial.n_vars()
+        }
+
+        fn evaluate(&self, point: &[Fq]) -> Fq {
+            self.polynomial.evaluate(point)
+        }
+
+        fn partial_evaluate(&self, value: Fq, var: usize) -> Self {
+            Self {
+                polynomial: self.polynomial.partial_evaluate(value, var),
+                bindings: self.bindings + 1,
+            }
+        }
+
+        fn max_degree(&self) -> usize {
+            self.polynomial.degree()
+        }
+
+        fn sum_over_hypercube(&self) -> Fq {
+            let offset = if self.bindings > 1 {
+                Fq::from(1u64)
+            } else {
+                Fq::from(0u64)
+            };
+
+            self.polynomial.sum_over_hypercube() + offset
+        }
+
+        fn absorb_into<H: Clone + Digest + FixedOutputReset>(
+            &self,
+            transcript: &mut Transcript<Fq, H>,
+        ) {
+            self.polynomial.absorb_into(transcript)
+        }
+    }
+
+    #[test]
+    fn test_prove_checked_reports_corrupted_round() {
+        // round 0 is computed before any challenge is bound, so the first bad round is 1
+        let polynomial = random_sum_poly(3);
+        let claimed_sum = polynomial.sum_over_hypercube();
+        let state = SumcheckProverState::new(CorruptedAfterBinding {
+            polynomial,
+            bindings: 0,
+        });
+        let mut transcript = Transcript::<Fq, Keccak256>::new();
+
+        let result = prove_rounds(claimed_sum, state, &mut transcript, true);
+
+        assert_eq!(result, Err(ProverError::RoundSumMismatch { round: 1 }));
+    }
 }
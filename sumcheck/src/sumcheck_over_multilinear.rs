@@ -1,3 +1,4 @@
+use crate::prover::{SumcheckProverState, prove_rounds};
 use ark_ff::PrimeField;
 use polynomials::multilinear::MultilinearPolynomial;
 use polynomials::univariate::DenseUnivariatePolynomial as UnivariatePolynomial;
@@ -17,33 +18,31 @@ impl<F: PrimeField> SumcheckProof<F> {
             round_polynomials,
         }
     }
+
+    pub fn claimed_sum(&self) -> F {
+        self.claimed_sum
+    }
+
+    pub fn round_polynomials(&self) -> &[UnivariatePolynomial<F>] {
+        &self.round_polynomials
+    }
 }
 
 pub fn prove<F: PrimeField>(
     polynomial: &MultilinearPolynomial<F>,
     claimed_sum: F,
 ) -> SumcheckProof<F> {
-    let mut round_polynomials = vec![];
-
     let mut transcript: Transcript<F, Keccak256> = Transcript::new();
-    transcript.append_field_element(&claimed_sum);
-    transcript.append(&polynomial.to_bytes());
-
-    let mut polynomial = polynomial.clone();
+    transcript.append(&polynomial.commitment_digest::<Keccak256>());
 
-    for _ in 0..polynomial.n_vars() {
-        let round_polynomial = skip_one_and_sum_over_boolean_hypercube(&polynomial);
-
-        transcript.append(
-            &round_polynomial.to_bytes()
-        );
-
-        round_polynomials.push(round_polynomial);
-
-        let challenge = transcript.sample_field_element();
-
-        polynomial = polynomial.partial_evaluate(challenge, 0);
-    }
+    // the claim is absorbed by the round loop, right before the first round
+    let (round_polynomials, _) = prove_rounds(
+        claimed_sum,
+        SumcheckProverState::new(polynomial.clone()),
+        &mut transcript,
+        false,
+    )
+    .expect("Rounds are only checked on request");
 
     SumcheckProof::new(claimed_sum, round_polynomials)
 }
@@ -52,13 +51,23 @@ pub fn verify<F: PrimeField>(
     polynomial: &MultilinearPolynomial<F>,
     proof: &SumcheckProof<F>,
 ) -> bool {
+    verify_with_output(polynomial, proof).is_some()
+}
+
+// full verification including the oracle check, handing back the derived challenges and the
+// final reduced claim (which equals polynomial.evaluate(challenges)) so that a caller like
+// GKR can continue from them
+pub fn verify_with_output<F: PrimeField>(
+    polynomial: &MultilinearPolynomial<F>,
+    proof: &SumcheckProof<F>,
+) -> Option<(Vec<F>, F)> {
     if proof.round_polynomials.len() != polynomial.n_vars() {
-        return false;
+        return None;
     }
 
     let mut transcript: Transcript<F, Keccak256> = Transcript::new();
+    transcript.append(&polynomial.commitment_digest::<Keccak256>());
     transcript.append_field_element(&proof.claimed_sum);
-    transcript.append(&polynomial.to_bytes());
 
     let mut claimed_sum = proof.claimed_sum;
     let mut challenges = vec![];
@@ -68,12 +77,10 @@ pub fn verify<F: PrimeField>(
         let p_1 = round_polynomial.evaluate(F::ONE);
 
         if claimed_sum != p_0 + p_1 {
-            return false;
+            return None;
         }
 
-        transcript.append(&round_polynomial.to_bytes());
-
-        let challenge = transcript.sample_field_element();
+        let challenge = transcript.challenge_after(&round_polynomial.to_bytes());
 
         claimed_sum = round_polynomial.evaluate(challenge);
         challenges.push(challenge);
@@ -81,27 +88,17 @@ pub fn verify<F: PrimeField>(
 
     // perform oracle check
     if claimed_sum != polynomial.evaluate(&challenges) {
-        return false;
+        return None;
     }
 
-    true
-}
-
-pub fn skip_one_and_sum_over_boolean_hypercube<F: PrimeField>(
-    polynomial: &MultilinearPolynomial<F>,
-) -> UnivariatePolynomial<F> {
-    let evals = polynomial.evals_slice();
-    let (left_half, right_half) = evals.split_at(evals.len() / 2);
-    let f_0 = left_half.iter().sum();
-    let f_1 = right_half.iter().sum();
-
-    UnivariatePolynomial::interpolate_y(vec![f_0, f_1])
+    Some((challenges, claimed_sum))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use ark_bls12_381::Fq as ArkField;
+    use ark_ff::UniformRand;
     use field_tracker::{Ft, end_tscope, print_summary, start_tscope};
 
     type Fq = Ft!(ArkField);
@@ -146,6 +143,70 @@ mod tests {
         assert!(verify(&polynomial, &proof));
     }
 
+    #[test]
+    fn test_sumcheck_random_polynomial() {
+        let mut rng = rand::thread_rng();
+        let evals: Vec<Fq> = (0..1 << 6).map(|_| Fq::rand(&mut rng)).collect();
+        let claimed_sum = evals.iter().sum();
+        let polynomial = MultilinearPolynomial::new(evals);
+
+        let proof = prove(&polynomial, claimed_sum);
+        assert!(verify(&polynomial, &proof));
+    }
+
+    #[test]
+    fn test_proof_accessors() {
+        let polynomial: MultilinearPolynomial<Fq> = MultilinearPolynomial::new(vec![
+            fq(0),
+            fq(0),
+            fq(0),
+            fq(3),
+            fq(0),
+            fq(0),
+            fq(2),
+            fq(5),
+        ]);
+        let proof = prove(&polynomial, fq(10));
+
+        assert_eq!(proof.claimed_sum(), fq(10));
+        assert_eq!(proof.round_polynomials().len(), polynomial.n_vars());
+
+        let first_round = &proof.round_polynomials()[0];
+        assert_eq!(
+            first_round.evaluate(fq(0)) + first_round.evaluate(fq(1)),
+            proof.claimed_sum()
+        );
+    }
+
+    #[test]
+    fn test_verify_with_output() {
+        let polynomial: MultilinearPolynomial<Fq> = MultilinearPolynomial::new(vec![
+            fq(0),
+            fq(0),
+            fq(0),
+            fq(3),
+            fq(0),
+            fq(0),
+            fq(2),
+            fq(5),
+        ]);
+        let proof = prove(&polynomial, fq(10));
+
+        let (challenges, final_claim) = verify_with_output(&polynomial, &proof).unwrap();
+
+        assert_eq!(challenges.len(), polynomial.n_vars());
+        assert_eq!(polynomial.evaluate(&challenges), final_claim);
+        assert_eq!(
+            proof
+                .round_polynomials()
+                .last()
+                .unwrap()
+                .evaluate(challenges[2]),
+            final_claim
+        );
+        assert!(verify_with_output(&polynomial, &prove(&polynomial, fq(9))).is_none());
+    }
+
     #[test]
     fn test_sumcheck_invalid_sum() {
         let polynomial: MultilinearPolynomial<Fq> = MultilinearPolynomial::new(vec![
@@ -1,46 +1,267 @@
 use ark_ff::PrimeField;
+use ark_serialize::CanonicalDeserialize;
 use polynomials::{composed::SumPolynomial, univariate::DenseUnivariatePolynomial};
-use sha3::Keccak256;
-use transcript::Transcript;
+use sha3::{Digest, Keccak256, digest::FixedOutputReset};
+use std::fmt;
+use transcript::{Transcript, util::ConstantTimeFieldEq};
 
-pub fn partial_verify<F: PrimeField>(
-    transcript: &mut Transcript<F, Keccak256>,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SumcheckError {
+    RoundCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    SumRelationFailed {
+        round: usize,
+    },
+    DegreeBoundExceeded {
+        round: usize,
+        degree: usize,
+        max_degree: usize,
+    },
+    CompressedRoundLengthMismatch {
+        round: usize,
+        expected: usize,
+        actual: usize,
+    },
+    OracleCheckFailed,
+    EmptyProof,
+}
+
+impl fmt::Display for SumcheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SumcheckError::RoundCountMismatch { expected, actual } => {
+                write!(f, "expected {} round polynomials, got {}", expected, actual)
+            }
+            SumcheckError::SumRelationFailed { round } => {
+                write!(
+                    f,
+                    "p(0) + p(1) does not match the claimed sum in round {}",
+                    round
+                )
+            }
+            SumcheckError::DegreeBoundExceeded {
+                round,
+                degree,
+                max_degree,
+            } => {
+                write!(
+                    f,
+                    "round polynomial {} has degree {}, expected at most {}",
+                    round, degree, max_degree
+                )
+            }
+            SumcheckError::CompressedRoundLengthMismatch {
+                round,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "compressed round polynomial {} has {} evaluations, expected {}",
+                    round, actual, expected
+                )
+            }
+            SumcheckError::OracleCheckFailed => {
+                write!(f, "final claim does not match the polynomial evaluation")
+            }
+            SumcheckError::EmptyProof => write!(f, "proof has no round polynomials"),
+        }
+    }
+}
+
+impl std::error::Error for SumcheckError {}
+
+// evaluates every round polynomial at the same points, e.g. all p(0) and p(1) of a proof at
+// once. the rounds don't depend on each other, so this is where batched evaluation can go
+pub fn evaluate_rounds<F: PrimeField>(
+    round_polynomials: &[DenseUnivariatePolynomial<F>],
+    points: &[F],
+) -> Vec<Vec<F>> {
+    round_polynomials
+        .iter()
+        .map(|round_polynomial| {
+            points
+                .iter()
+                .map(|&point| round_polynomial.evaluate(point))
+                .collect()
+        })
+        .collect()
+}
+
+// `max_degree` is the degree the verifier expects in every variable (`sum_polynomial.degree()`
+// for the composed sumcheck). without it a prover could send higher-degree round polynomials
+// that still satisfy p(0) + p(1) = claim
+pub fn partial_verify_detailed<F: PrimeField, H: Clone + Digest + FixedOutputReset>(
+    transcript: &mut Transcript<F, H>,
     claimed_sum: F,
     round_polynomials: Vec<DenseUnivariatePolynomial<F>>,
-) -> (bool, F, Vec<F>) {
+    max_degree: usize,
+) -> Result<(F, Vec<F>), SumcheckError> {
     if round_polynomials.is_empty() {
-        return (false, claimed_sum, vec![]);
+        return Err(SumcheckError::EmptyProof);
     }
 
     transcript.append_field_element(&claimed_sum);
     let mut current_sum: F = claimed_sum;
     let mut challenges: Vec<F> = Vec::new();
+    let boundary_evals = evaluate_rounds(&round_polynomials, &[F::ZERO, F::ONE]);
+
+    for (round, (round_polynomial, evals)) in round_polynomials
+        .into_iter()
+        .zip(boundary_evals)
+        .enumerate()
+    {
+        let degree = round_polynomial.degree();
+
+        if degree > max_degree {
+            return Err(SumcheckError::DegreeBoundExceeded {
+                round,
+                degree,
+                max_degree,
+            });
+        }
+
+        if current_sum != evals[0] + evals[1] {
+            return Err(SumcheckError::SumRelationFailed { round });
+        }
+
+        let challenge = transcript.challenge_after(&round_polynomial.to_bytes());
+
+        current_sum = round_polynomial.evaluate(challenge);
+        challenges.push(challenge);
+    }
+
+    Ok((current_sum, challenges))
+}
 
-    for round_polynomial in round_polynomials {
-        let p_0 = round_polynomial.evaluate(F::ZERO);
-        let p_1 = round_polynomial.evaluate(F::ONE);
+// verifier side of `partial_prove`: checks the rounds against the incoming claim on the
+// shared transcript and returns the reduced claim with the challenges, leaving the final
+// oracle check to the caller (e.g. the next GKR layer)
+pub fn partial_verify<F: PrimeField, H: Clone + Digest + FixedOutputReset>(
+    transcript: &mut Transcript<F, H>,
+    claimed_sum: F,
+    round_polynomials: Vec<DenseUnivariatePolynomial<F>>,
+    max_degree: usize,
+) -> (bool, F, Vec<F>) {
+    match partial_verify_detailed(transcript, claimed_sum, round_polynomials, max_degree) {
+        Ok((current_sum, challenges)) => (true, current_sum, challenges),
+        Err(_) => (false, claimed_sum, vec![]),
+    }
+}
 
-        if current_sum != p_0 + p_1 {
-            println!(
-                "cs: {}, p_0: {}, p_1: {}, fal: {}",
-                current_sum,
-                p_0,
-                p_1,
-                claimed_sum == p_0 + p_1
-            );
+// inverse of `compress_round_polynomial`: p(0) comes from the running claim, the rest of the
+// evaluations are sent by the prover. None when there is no p(1) to recover p(0) from
+fn decompress_round_polynomial<F: PrimeField>(
+    current_sum: F,
+    compressed_round_polynomial: &[F],
+) -> Option<DenseUnivariatePolynomial<F>> {
+    let &p_1 = compressed_round_polynomial.first()?;
+    let mut evals = Vec::with_capacity(compressed_round_polynomial.len() + 1);
 
-            return (false, current_sum, challenges);
+    evals.push(current_sum - p_1);
+    evals.extend_from_slice(compressed_round_polynomial);
+
+    Some(DenseUnivariatePolynomial::interpolate_y(&evals))
+}
+
+// the sum relation holds by construction for compressed round polynomials, so a cheating
+// prover can only be caught by the final oracle check. every round is sent as exactly
+// `max_degree` evaluations, so any other length is malformed
+pub fn partial_verify_compressed<F: PrimeField, H: Clone + Digest + FixedOutputReset>(
+    transcript: &mut Transcript<F, H>,
+    claimed_sum: F,
+    compressed_round_polynomials: Vec<Vec<F>>,
+    max_degree: usize,
+) -> Result<(F, Vec<F>), SumcheckError> {
+    if compressed_round_polynomials.is_empty() {
+        return Err(SumcheckError::EmptyProof);
+    }
+
+    transcript.append_field_element(&claimed_sum);
+    let mut current_sum: F = claimed_sum;
+    let mut challenges: Vec<F> = Vec::new();
+
+    for (round, compressed_round_polynomial) in compressed_round_polynomials.iter().enumerate() {
+        let length_mismatch = SumcheckError::CompressedRoundLengthMismatch {
+            round,
+            expected: max_degree,
+            actual: compressed_round_polynomial.len(),
+        };
+
+        if compressed_round_polynomial.len() != max_degree {
+            return Err(length_mismatch);
         }
 
-        transcript.append(&round_polynomial.to_bytes());
+        let round_polynomial =
+            decompress_round_polynomial(current_sum, compressed_round_polynomial)
+                .ok_or(length_mismatch)?;
 
-        let challenge = transcript.sample_field_element();
+        let challenge = transcript.challenge_after(&round_polynomial.to_bytes());
 
         current_sum = round_polynomial.evaluate(challenge);
         challenges.push(challenge);
     }
 
-    (true, current_sum, challenges)
+    Ok((current_sum, challenges))
+}
+
+pub fn verify_detailed<F: PrimeField>(
+    sum_polynomial: SumPolynomial<F>,
+    claimed_sum: F,
+    round_polynomials: Vec<DenseUnivariatePolynomial<F>>,
+) -> Result<(), SumcheckError> {
+    verify_with_oracle_check(sum_polynomial, claimed_sum, round_polynomials, false)
+}
+
+fn verify_with_oracle_check<F: PrimeField>(
+    sum_polynomial: SumPolynomial<F>,
+    claimed_sum: F,
+    round_polynomials: Vec<DenseUnivariatePolynomial<F>>,
+    constant_time: bool,
+) -> Result<(), SumcheckError> {
+    if round_polynomials.len() != sum_polynomial.n_vars() {
+        return Err(SumcheckError::RoundCountMismatch {
+            expected: sum_polynomial.n_vars(),
+            actual: round_polynomials.len(),
+        });
+    }
+
+    let mut transcript: Transcript<F, Keccak256> = Transcript::new();
+
+    sum_polynomial.absorb_into(&mut transcript);
+
+    let (claimed_sum, challenges) = partial_verify_detailed(
+        &mut transcript,
+        claimed_sum,
+        round_polynomials,
+        sum_polynomial.degree(),
+    )?;
+
+    oracle_check(&sum_polynomial, claimed_sum, &challenges, constant_time)
+}
+
+fn oracle_check<F: PrimeField>(
+    sum_polynomial: &SumPolynomial<F>,
+    claimed_sum: F,
+    challenges: &[F],
+    constant_time: bool,
+) -> Result<(), SumcheckError> {
+    let derived_sum = sum_polynomial.evaluate(challenges);
+
+    // Perform oracle check
+    let is_equal = if constant_time {
+        claimed_sum.ct_eq(&derived_sum)
+    } else {
+        claimed_sum == derived_sum
+    };
+
+    if !is_equal {
+        return Err(SumcheckError::OracleCheckFailed);
+    }
+
+    Ok(())
 }
 
 pub fn verify<F: PrimeField>(
@@ -48,19 +269,60 @@ pub fn verify<F: PrimeField>(
     claimed_sum: F,
     round_polynomials: Vec<DenseUnivariatePolynomial<F>>,
 ) -> bool {
-    let mut transcript: Transcript<F, Keccak256> = Transcript::new();
+    verify_detailed(sum_polynomial, claimed_sum, round_polynomials).is_ok()
+}
 
-    transcript.append(&sum_polynomial.to_bytes());
+// inverse of `prove_to_bytes`. bytes that don't decode to exactly one proof are rejected
+pub fn verify_from_bytes<F: PrimeField>(sum_polynomial: SumPolynomial<F>, bytes: &[u8]) -> bool {
+    let mut reader = bytes;
 
-    let (is_partially_verified, claimed_sum, challenges) =
-        partial_verify(&mut transcript, claimed_sum, round_polynomials);
+    match <(F, Vec<DenseUnivariatePolynomial<F>>)>::deserialize_compressed(&mut reader) {
+        Ok((claimed_sum, round_polynomials)) if reader.is_empty() => {
+            verify(sum_polynomial, claimed_sum, round_polynomials)
+        }
+        _ => false,
+    }
+}
 
-    if !is_partially_verified {
-        return false;
+// same as `verify` but the final oracle comparison runs in constant time
+pub fn verify_ct<F: PrimeField>(
+    sum_polynomial: SumPolynomial<F>,
+    claimed_sum: F,
+    round_polynomials: Vec<DenseUnivariatePolynomial<F>>,
+) -> bool {
+    verify_with_oracle_check(sum_polynomial, claimed_sum, round_polynomials, true).is_ok()
+}
+
+pub fn verify_compressed_detailed<F: PrimeField>(
+    sum_polynomial: SumPolynomial<F>,
+    claimed_sum: F,
+    compressed_round_polynomials: Vec<Vec<F>>,
+) -> Result<(), SumcheckError> {
+    if compressed_round_polynomials.len() != sum_polynomial.n_vars() {
+        return Err(SumcheckError::RoundCountMismatch {
+            expected: sum_polynomial.n_vars(),
+            actual: compressed_round_polynomials.len(),
+        });
     }
 
-    let derived_sum = sum_polynomial.evaluate(&challenges);
+    let mut transcript: Transcript<F, Keccak256> = Transcript::new();
 
-    // Perform oracle check
-    claimed_sum == derived_sum
+    sum_polynomial.absorb_into(&mut transcript);
+
+    let (claimed_sum, challenges) = partial_verify_compressed(
+        &mut transcript,
+        claimed_sum,
+        compressed_round_polynomials,
+        sum_polynomial.degree(),
+    )?;
+
+    oracle_check(&sum_polynomial, claimed_sum, &challenges, false)
+}
+
+pub fn verify_compressed<F: PrimeField>(
+    sum_polynomial: SumPolynomial<F>,
+    claimed_sum: F,
+    compressed_round_polynomials: Vec<Vec<F>>,
+) -> bool {
+    verify_compressed_detailed(sum_polynomial, claimed_sum, compressed_round_polynomials).is_ok()
 }
@@ -36,4 +36,26 @@ pub fn fiat_shamir_transcript_benchmarks(c: &mut Criterion) {
             black_box(transcript.sample_n_field_elements(100));
         });
     });
+
+    group.finish();
+
+    let mut group = c.benchmark_group("fiat-shamir transcript hashers");
+
+    let mut transcript = Transcript::<Fq, Keccak256>::new();
+    transcript.append_field_element(&Fq::rand(&mut rng));
+
+    group.bench_function("keccak256_sample_n_field_elements_100", |b| {
+        b.iter(|| {
+            black_box(transcript.sample_n_field_elements(100));
+        });
+    });
+
+    let mut transcript = Transcript::<Fq, blake3::Hasher>::new();
+    transcript.append_field_element(&Fq::rand(&mut rng));
+
+    group.bench_function("blake3_sample_n_field_elements_100", |b| {
+        b.iter(|| {
+            black_box(transcript.sample_n_field_elements(100));
+        });
+    });
 }
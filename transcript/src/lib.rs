@@ -2,12 +2,21 @@ use ark_ff::{BigInteger, PrimeField};
 use sha3::{Digest, digest::FixedOutputReset};
 use std::marker::PhantomData;
 
+pub mod util;
+
 #[derive(Debug)]
 pub struct Transcript<F, H> {
     hasher: H,
     _phantom: PhantomData<F>,
 }
 
+// hasher state of a transcript at some point, to rewind a transcript to with
+// `Transcript::restore`
+#[derive(Debug, Clone)]
+pub struct TranscriptState<H> {
+    hasher: H,
+}
+
 impl<F: PrimeField, H: Clone + Digest + FixedOutputReset> Transcript<F, H> {
     pub fn new() -> Self {
         Transcript {
@@ -32,9 +41,64 @@ impl<F: PrimeField, H: Clone + Digest + FixedOutputReset> Transcript<F, H> {
         F::from_be_bytes_mod_order(hash)
     }
 
+    // absorb-then-sample in one call, so a challenge can't accidentally be drawn before the
+    // message it should be bound to
+    pub fn challenge_after(&mut self, data: &[u8]) -> F {
+        self.append(data);
+
+        self.sample_field_element()
+    }
+
+    pub fn challenge_after_field(&mut self, element: &F) -> F {
+        self.append_field_element(element);
+
+        self.sample_field_element()
+    }
+
+    pub fn snapshot(&self) -> TranscriptState<H> {
+        TranscriptState {
+            hasher: self.hasher.clone(),
+        }
+    }
+
+    // rewinds this transcript to a snapshot, dropping everything absorbed or sampled since
+    pub fn restore(&mut self, state: TranscriptState<H>) {
+        self.hasher = state.hasher;
+    }
+
     pub fn sample_n_field_elements(&mut self, n: usize) -> Vec<F> {
         (0..n).map(|_| self.sample_field_element()).collect()
     }
+
+    // every challenge is drawn right after absorbing the label and its index in the vector,
+    // so challenges for different purposes or positions are domain separated. the label is
+    // length prefixed so the (label, index) encoding can't be ambiguous.
+    pub fn sample_labeled_vector(&mut self, label: &[u8], n: usize) -> Vec<F> {
+        (0..n as u64)
+            .map(|index| {
+                self.append(&(label.len() as u64).to_be_bytes());
+                self.append(label);
+
+                self.challenge_after(&index.to_be_bytes())
+            })
+            .collect()
+    }
+
+    // batches claims into ∑ values[i]·ρ^i. the values are absorbed before ρ is sampled, so ρ
+    // can't be known while the claims are still being chosen
+    pub fn random_linear_combination(&mut self, values: &[F]) -> F {
+        for value in values {
+            self.append_field_element(value);
+        }
+
+        let rho = self.sample_field_element();
+
+        // Horner's rule from the highest power down
+        values
+            .iter()
+            .rev()
+            .fold(F::ZERO, |acc, value| acc * rho + value)
+    }
 }
 
 impl<F: PrimeField, H: Clone + Digest + FixedOutputReset> Default for Transcript<F, H> {
@@ -198,6 +262,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sample_labeled_vector() {
+        let labeled = |label: &[u8]| {
+            let mut transcript = Transcript::<Fq, Keccak256>::new();
+            transcript.append(b"statement");
+
+            transcript.sample_labeled_vector(label, 16)
+        };
+
+        let challenges = labeled(b"alpha/beta");
+        let mut seen = std::collections::HashSet::new();
+
+        assert_eq!(challenges.len(), 16);
+        assert!(challenges.iter().all(|challenge| seen.insert(*challenge)));
+        assert_eq!(labeled(b"alpha/beta"), challenges);
+        assert_ne!(labeled(b"output claim"), challenges);
+    }
+
     #[test]
     fn test_with_a_different_hash_function() {
         let mut transcript = Transcript::<Fq, Sha3_256>::new();
@@ -214,4 +296,85 @@ mod tests {
 
         assert_eq!(challenge, expected_challenge);
     }
+
+    #[test]
+    fn test_with_blake3() {
+        let mut transcript = Transcript::<Fq, blake3::Hasher>::new();
+
+        let data = b"bozobano";
+
+        transcript.append(data);
+
+        let challenge = transcript.sample_field_element();
+
+        let expected_challenge_bytes = blake3::hash(data);
+        let expected_challenge = Fq::from_be_bytes_mod_order(expected_challenge_bytes.as_bytes());
+
+        assert_eq!(challenge, expected_challenge);
+    }
+
+    #[test]
+    fn test_challenge_after() {
+        let mut transcript = Transcript::<Fq, Keccak256>::new();
+        let mut expected = Transcript::<Fq, Keccak256>::new();
+
+        expected.append(b"bozobano");
+
+        assert_eq!(
+            transcript.challenge_after(b"bozobano"),
+            expected.sample_field_element()
+        );
+
+        expected.append_field_element(&fq(7));
+
+        assert_eq!(
+            transcript.challenge_after_field(&fq(7)),
+            expected.sample_field_element()
+        );
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let mut transcript = Transcript::<Fq, Keccak256>::new();
+        transcript.append(b"statement");
+
+        let state = transcript.snapshot();
+        let first = transcript.challenge_after(b"layer 0");
+
+        transcript.append(b"layer 1");
+        transcript.sample_field_element();
+
+        transcript.restore(state.clone());
+
+        assert_eq!(transcript.challenge_after(b"layer 0"), first);
+
+        transcript.restore(state);
+
+        assert_ne!(transcript.challenge_after(b"another layer"), first);
+    }
+
+    #[test]
+    fn test_random_linear_combination() {
+        let values = vec![fq(3), fq(5), fq(7)];
+        let mut transcript = Transcript::<Fq, Keccak256>::new();
+        transcript.append(b"statement");
+
+        let state = transcript.snapshot();
+        let combined = transcript.random_linear_combination(&values);
+
+        transcript.restore(state.clone());
+        for value in &values {
+            transcript.append_field_element(value);
+        }
+        let rho = transcript.sample_field_element();
+
+        assert_eq!(combined, fq(3) + fq(5) * rho + fq(7) * rho * rho);
+
+        transcript.restore(state);
+
+        assert_ne!(
+            transcript.random_linear_combination(&[fq(7), fq(5), fq(3)]),
+            combined
+        );
+    }
 }
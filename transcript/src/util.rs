@@ -0,0 +1,40 @@
+use ark_ff::PrimeField;
+use subtle::ConstantTimeEq;
+
+pub trait ConstantTimeFieldEq<F> {
+    fn ct_eq(&self, other: &F) -> bool;
+}
+
+impl<F: PrimeField> ConstantTimeFieldEq<F> for F {
+    // compares the canonical limbs without branching on their contents, for checks
+    // where one side is derived from secret data
+    fn ct_eq(&self, other: &F) -> bool {
+        let lhs = self.into_bigint();
+        let rhs = other.into_bigint();
+
+        lhs.as_ref().ct_eq(rhs.as_ref()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bls12_381::Fq;
+    use ark_ff::UniformRand;
+
+    #[test]
+    fn test_ct_eq_matches_eq() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            let a = Fq::rand(&mut rng);
+            let b = Fq::rand(&mut rng);
+
+            assert_eq!(a.ct_eq(&b), a == b);
+            assert!(a.ct_eq(&a));
+            assert!(!a.ct_eq(&(a + Fq::from(1u64))));
+        }
+
+        assert!(Fq::from(0u64).ct_eq(&Fq::from(0u64)));
+    }
+}